@@ -25,7 +25,9 @@ use crate::{
         self,
         analyzer::AnalyzerError,
         parser::{Parser, ParserError},
-        statement::{Column, Constraint, Create, DataType, Statement, Value},
+        statement::{
+            Column, Constraint, Create, DataType, Expression, Statement, TableConstraint, Value,
+        },
     },
     storage::{tuple, BTree, BTreeKeyComparator, FixedSizeMemCmp},
     vm::{
@@ -158,6 +160,8 @@ pub(crate) enum SqlError {
     InvalidColumn(String),
     /// Duplicated UNIQUE columns, duplicated PRIMARY KEY columns, etc.
     DuplicatedKey(Value),
+    /// Row that doesn't satisfy the given `CHECK` constraint.
+    CheckViolation(Expression),
     /// Errors caught by the [`sql::analyzer`].
     AnalyzerError(AnalyzerError),
     /// Data type errors. Trying to add numbers to strings, etc.
@@ -192,6 +196,7 @@ impl Display for SqlError {
             Self::InvalidTable(name) => write!(f, "invalid table '{name}'"),
             Self::InvalidColumn(name) => write!(f, "invalid column '{name}'"),
             Self::DuplicatedKey(key) => write!(f, "duplicated key {key}"),
+            Self::CheckViolation(expr) => write!(f, "CHECK constraint failed: {expr}"),
             Self::AnalyzerError(analyzer_error) => write!(f, "{analyzer_error}"),
             Self::VmError(vm_error) => write!(f, "{vm_error}"),
             Self::TypeError(type_error) => write!(f, "{type_error}"),
@@ -221,7 +226,7 @@ impl Display for DbError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::Io(e) => write!(f, "{e}"),
-            Self::Parser(e) => write!(f, "{e}"),
+            Self::Parser(e) => f.write_str(&e.render(&e.input)),
             Self::Sql(e) => write!(f, "{e}"),
             Self::Corrupted(message) => f.write_str(message),
             Self::NoMem => f.write_str("our of memory"),
@@ -422,6 +427,9 @@ pub(crate) struct TableMetadata {
     pub schema: Schema,
     /// All the indexes associated to this table.
     pub indexes: Vec<IndexMetadata>,
+    /// Table level `CHECK` constraints. Column level ones are stored in the
+    /// [`Column::constraints`] of the schema.
+    pub checks: Vec<Expression>,
     /// Next [`RowId`] for this table.
     row_id: RowId,
 }
@@ -612,7 +620,12 @@ impl TryFrom<&[&str]> for Context {
             let statement = Parser::new(sql).parse_statement()?;
 
             match statement {
-                Statement::Create(Create::Table { name, columns }) => {
+                Statement::Create(Create::Table {
+                    name,
+                    columns,
+                    table_constraints,
+                    ..
+                }) => {
                     let mut schema = Schema::from(&columns);
                     schema.prepend_row_id();
 
@@ -622,6 +635,7 @@ impl TryFrom<&[&str]> for Context {
                         row_id: 1,
                         schema,
                         indexes: vec![],
+                        checks: TableConstraint::checks(table_constraints),
                     };
                     root += 1;
 
@@ -630,6 +644,11 @@ impl TryFrom<&[&str]> for Context {
                             let index_name = match constraint {
                                 Constraint::PrimaryKey => format!("{name}_pk_index"),
                                 Constraint::Unique => format!("{name}_{}_uq_index", column.name),
+                                Constraint::NotNull
+                                | Constraint::References { .. }
+                                | Constraint::Default(_)
+                                | Constraint::Check(_)
+                                | Constraint::AutoIncrement => continue,
                             };
 
                             metadata.indexes.push(IndexMetadata {
@@ -649,10 +668,11 @@ impl TryFrom<&[&str]> for Context {
 
                 Statement::Create(Create::Index {
                     name,
-                    column,
+                    mut columns,
                     unique,
                     ..
                 }) if unique => {
+                    let column = columns.swap_remove(0);
                     let table = context.table_metadata(&name)?;
                     let index_col = table.schema.columns[table.schema.index_of(&column).unwrap()].clone();
 
@@ -764,6 +784,7 @@ impl<F: Seek + Read + Write + FileOps> Database<F> {
                 row_id: self.load_next_row_id(MKDB_META_ROOT)?,
                 schema,
                 indexes: vec![],
+                checks: vec![],
             });
         }
 
@@ -773,6 +794,7 @@ impl<F: Seek + Read + Write + FileOps> Database<F> {
             row_id: 1,
             schema: Schema::empty(),
             indexes: Vec::new(),
+            checks: Vec::new(),
         };
 
         let mut found_table_definition = false;
@@ -795,7 +817,11 @@ impl<F: Seek + Read + Write + FileOps> Database<F> {
 
             match &tuple[schema.index_of("sql").ok_or(corrupted_error())?] {
                 Value::String(sql) => match Parser::new(sql).parse_statement()? {
-                    Statement::Create(Create::Table { columns, .. }) => {
+                    Statement::Create(Create::Table {
+                        columns,
+                        table_constraints,
+                        ..
+                    }) => {
                         assert!(
                             !found_table_definition,
                             "multiple definitions of table '{table}'"
@@ -803,6 +829,7 @@ impl<F: Seek + Read + Write + FileOps> Database<F> {
 
                         metadata.root = *root as PageNumber;
                         metadata.schema = Schema::new(columns);
+                        metadata.checks = TableConstraint::checks(table_constraints);
 
                         // Tables tha don't have an integer primary key as the
                         // first field will use a hidden primary key that we
@@ -815,11 +842,12 @@ impl<F: Seek + Read + Write + FileOps> Database<F> {
                     }
 
                     Statement::Create(Create::Index {
-                        column,
+                        mut columns,
                         name,
                         unique,
                         ..
                     }) => {
+                        let column = columns.swap_remove(0);
                         // The table schema should be loaded by this time
                         // because it's impossible to define an index unless the
                         // table exists and the results are returned sorted by
@@ -940,7 +968,7 @@ impl<F: Seek + Read + Write + FileOps> Database<F> {
             | Statement::Rollback => Exec::Statement(statement),
 
             Statement::Explain(inner) => match &*inner {
-                Statement::Select { .. }
+                Statement::Select(_)
                 | Statement::Insert { .. }
                 | Statement::Update { .. }
                 | Statement::Delete { .. } => {
@@ -1186,7 +1214,9 @@ mod tests {
         sql::{
             analyzer::AnalyzerError,
             parser::Parser,
-            statement::{Column, DataType, Expression, Value},
+            statement::{
+                BinaryOperator, Column, Constraint, DataType, Expression, UnaryOperator, Value,
+            },
         },
         storage::{reassemble_payload, tuple, Cursor},
         vm::VmDataType,
@@ -1542,6 +1572,123 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn insert_multiple_rows() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255), age INT);")?;
+        db.exec(
+            "INSERT INTO users(name, age, id) VALUES ('John Doe', 18, 2), ('Jane Doe', 22, 1);",
+        )?;
+
+        let query = db.exec("SELECT * FROM users;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+                Column::new("age", DataType::Int),
+            ]),
+            tuples: vec![
+                vec![
+                    Value::Number(1),
+                    Value::String("Jane Doe".into()),
+                    Value::Number(22)
+                ],
+                vec![
+                    Value::Number(2),
+                    Value::String("John Doe".into()),
+                    Value::Number(18)
+                ],
+            ]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_multiple_rows_with_row_id_and_defaults() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (name VARCHAR(255), age INT DEFAULT 0);")?;
+        db.exec("INSERT INTO users(name) VALUES ('John Doe'), ('Jane Doe');")?;
+
+        let query = db.exec("SELECT * FROM users;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![Column::new("name", DataType::Varchar(255)), Column {
+                name: "age".into(),
+                data_type: DataType::Int,
+                constraints: vec![Constraint::Default(Expression::Value(Value::Number(0)))],
+            },]),
+            tuples: vec![
+                vec![Value::String("John Doe".into()), Value::Number(0)],
+                vec![Value::String("Jane Doe".into()), Value::Number(0)],
+            ]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_without_columns() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255), age INT);")?;
+        db.exec("INSERT INTO users VALUES (1, 'John Doe', 18);")?;
+        db.exec("INSERT INTO users VALUES (3, 'Jack Doe', 30), (2, 'Jane Doe', 22);")?;
+
+        let query = db.exec("SELECT * FROM users;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+                Column::new("age", DataType::Int),
+            ]),
+            tuples: vec![
+                vec![
+                    Value::Number(1),
+                    Value::String("John Doe".into()),
+                    Value::Number(18)
+                ],
+                vec![
+                    Value::Number(2),
+                    Value::String("Jane Doe".into()),
+                    Value::Number(22)
+                ],
+                vec![
+                    Value::Number(3),
+                    Value::String("Jack Doe".into()),
+                    Value::Number(30)
+                ],
+            ]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_escaped_quotes() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        // The default value goes through the SQL stored in the meta table.
+        db.exec(
+            r#"CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(20) DEFAULT 'say "hi"');"#,
+        )?;
+        db.exec("INSERT INTO users (id, name) VALUES (1, 'O''Brien');")?;
+        db.exec("INSERT INTO users (id) VALUES (2);")?;
+
+        let query = db.exec("SELECT name FROM users;")?;
+
+        assert_eq!(query.tuples, vec![
+            vec![Value::String("O'Brien".into())],
+            vec![Value::String("say \"hi\"".into())],
+        ]);
+
+        Ok(())
+    }
+
     #[test]
     fn insert_expressions() -> Result<(), DbError> {
         let mut db = init_database()?;
@@ -1777,7 +1924,184 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn select_case_expression() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE products (id INT PRIMARY KEY, price INT);")?;
+        db.exec("INSERT INTO products(id, price) VALUES (1, 50), (2, 150);")?;
+
+        let query = db.exec(
+            "SELECT id, CASE WHEN price > 100 THEN 'hi' ELSE 'lo' END AS range FROM products;",
+        )?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("range", DataType::Varchar(65535)),
+            ]),
+            tuples: vec![vec![Value::Number(1), Value::String("lo".into())], vec![
+                Value::Number(2),
+                Value::String("hi".into())
+            ],]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn quoted_identifiers() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE orders (id INT PRIMARY KEY, `order` INT UNIQUE);")?;
+        db.exec("INSERT INTO orders (id, `order`) VALUES (1, 10), (2, 20);")?;
+
+        let query = db.exec("SELECT `order` FROM orders WHERE `order` > 10;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![Column {
+                name: "order".into(),
+                data_type: DataType::Int,
+                constraints: vec![Constraint::Unique],
+            }]),
+            tuples: vec![vec![Value::Number(20)]]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_reserved_keywords_as_identifiers() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec(
+            "CREATE TABLE settings (key INT PRIMARY KEY, value INT UNIQUE, name VARCHAR(255));",
+        )?;
+        db.exec("INSERT INTO settings (key, value, name) VALUES (1, 10, 'a'), (2, 20, 'b');")?;
+
+        let query = db.exec("SELECT key, value, name FROM settings WHERE value > 10;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("key", DataType::Int),
+                Column::unique("value", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+            ]),
+            tuples: vec![vec![
+                Value::Number(2),
+                Value::Number(20),
+                Value::String("b".into())
+            ]]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_without_from() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        let query = db.exec("SELECT 1 + 1, 'hello' AS greeting;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::new("2", DataType::BigInt),
+                Column::new("greeting", DataType::Varchar(65535)),
+            ]),
+            tuples: vec![vec![Value::Number(2), Value::String("hello".into())]]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn select_without_from_with_where() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        let query = db.exec("SELECT 1 WHERE 2 > 1;")?;
+        assert_eq!(query.tuples, vec![vec![Value::Number(1)]]);
+
+        let query = db.exec("SELECT 1 WHERE 1 > 2;")?;
+        assert!(query.tuples.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn skip_empty_statements() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        let query = db.exec(";; ;\n SELECT 1 + 1;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![Column::new("2", DataType::BigInt)]),
+            tuples: vec![vec![Value::Number(2)]]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn exec_without_final_semicolon() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255))")?;
+        db.exec("INSERT INTO users(id, name) VALUES (1, 'John Doe')")?;
+
+        let query = db.exec("SELECT * FROM users")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+            ]),
+            tuples: vec![vec![Value::Number(1), Value::String("John Doe".into())]]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn exec_only_one_statement() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        assert_eq!(
+            db.exec("SELECT 1; SELECT 2;"),
+            Err(SqlError::Other("only one statement can be executed at a time".into()).into())
+        );
+        assert_eq!(
+            db.exec(" ;; "),
+            Err(SqlError::Other("nothing to execute".into()).into())
+        );
+
+        Ok(())
+    }
+
     #[cfg(not(miri))]
+    #[test]
+    fn select_with_aliases() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE products (id INT PRIMARY KEY, price INT, discount INT);")?;
+        db.exec("INSERT INTO products(id, price, discount) VALUES (1, 100, 5);")?;
+        db.exec("INSERT INTO products(id, price, discount) VALUES (2, 250, 10);")?;
+
+        let query = db.exec("SELECT id AS product, price - discount total FROM products;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("product", DataType::Int),
+                Column::new("total", DataType::BigInt),
+            ]),
+            tuples: vec![vec![Value::Number(1), Value::Number(95)], vec![
+                Value::Number(2),
+                Value::Number(240)
+            ],]
+        });
+
+        Ok(())
+    }
+
     #[test]
     fn select_many() -> Result<(), DbError> {
         let mut db = init_database_with(DbConf {
@@ -2149,6 +2473,113 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn select_where_like_on_indexed_column() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec(
+            "CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255), email VARCHAR(255) UNIQUE);",
+        )?;
+        db.exec("INSERT INTO users(id, name, email) VALUES (1, 'Alex', 'alex@email.com');")?;
+        db.exec("INSERT INTO users(id, name, email) VALUES (2, 'Bob', 'bob@email.com');")?;
+        db.exec("INSERT INTO users(id, name, email) VALUES (3, 'David', 'david@other.com');")?;
+
+        let query =
+            db.exec("SELECT * FROM users WHERE email > 'b' AND email LIKE '%@email.com';")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+                Column::unique("email", DataType::Varchar(255)),
+            ]),
+            tuples: vec![vec![
+                Value::Number(2),
+                Value::String("Bob".into()),
+                Value::String("bob@email.com".into()),
+            ],]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_small_integers() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec(
+            "CREATE TABLE stats (id SMALLINT PRIMARY KEY, delta SMALLINT, hits SMALLINT UNSIGNED);",
+        )?;
+        db.exec("INSERT INTO stats(id, delta, hits) VALUES (1, -32768, 65535);")?;
+        db.exec("INSERT INTO stats(id, delta, hits) VALUES (2, 32767, 0);")?;
+
+        let query = db.exec("SELECT * FROM stats;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::SmallInt),
+                Column::new("delta", DataType::SmallInt),
+                Column::new("hits", DataType::UnsignedSmallInt),
+            ]),
+            tuples: vec![
+                vec![
+                    Value::Number(1),
+                    Value::Number(-32768),
+                    Value::Number(65535)
+                ],
+                vec![Value::Number(2), Value::Number(32767), Value::Number(0)],
+            ]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn insert_with_default_values() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255) DEFAULT 'unknown', age INT DEFAULT -1);")?;
+        db.exec("INSERT INTO users(id) VALUES (1);")?;
+        db.exec("INSERT INTO users(age, id) VALUES (20, 2);")?;
+
+        let query = db.exec("SELECT * FROM users;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column {
+                    name: "name".into(),
+                    data_type: DataType::Varchar(255),
+                    constraints: vec![Constraint::Default(Expression::Value(Value::String(
+                        "unknown".into()
+                    )))],
+                },
+                Column {
+                    name: "age".into(),
+                    data_type: DataType::Int,
+                    constraints: vec![Constraint::Default(Expression::UnaryOperation {
+                        operator: UnaryOperator::Minus,
+                        expr: Box::new(Expression::Value(Value::Number(1))),
+                    })],
+                },
+            ]),
+            tuples: vec![
+                vec![
+                    Value::Number(1),
+                    Value::String("unknown".into()),
+                    Value::Number(-1)
+                ],
+                vec![
+                    Value::Number(2),
+                    Value::String("unknown".into()),
+                    Value::Number(20)
+                ],
+            ]
+        });
+
+        Ok(())
+    }
+
     #[test]
     fn select_where_auto_index_exact_not_found() -> Result<(), DbError> {
         let mut db = init_database()?;
@@ -3881,6 +4312,59 @@ mod tests {
         ])
     }
 
+    #[test]
+    fn begin_and_rollback() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255));")?;
+        db.exec("INSERT INTO users (id, name) VALUES (1, 'John Doe');")?;
+
+        db.exec("BEGIN;")?;
+        db.exec("INSERT INTO users (id, name) VALUES (2, 'Jane Doe');")?;
+        db.exec("ROLLBACK;")?;
+
+        let query = db.exec("SELECT * FROM users;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+            ]),
+            tuples: vec![vec![Value::Number(1), Value::String("John Doe".into())]]
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn start_transaction_and_commit() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255));")?;
+
+        db.exec("START TRANSACTION;")?;
+        db.exec("INSERT INTO users (id, name) VALUES (1, 'John Doe');")?;
+        db.exec("INSERT INTO users (id, name) VALUES (2, 'Jane Doe');")?;
+        db.exec("COMMIT;")?;
+
+        assert!(!db.active_transaction());
+
+        let query = db.exec("SELECT * FROM users;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+            ]),
+            tuples: vec![
+                vec![Value::Number(1), Value::String("John Doe".into())],
+                vec![Value::Number(2), Value::String("Jane Doe".into())],
+            ]
+        });
+
+        Ok(())
+    }
+
     #[test]
     fn drop_table() -> Result<(), DbError> {
         let page_size = 1024;
@@ -3980,6 +4464,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn create_table_if_not_exists() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255));")?;
+        db.exec("INSERT INTO users (id, name) VALUES (1, 'John Doe');")?;
+        db.exec("CREATE TABLE IF NOT EXISTS users (id INT PRIMARY KEY, name VARCHAR(255));")?;
+
+        let query = db.exec("SELECT * FROM users;")?;
+
+        assert_eq!(query, QuerySet {
+            schema: Schema::new(vec![
+                Column::primary_key("id", DataType::Int),
+                Column::new("name", DataType::Varchar(255)),
+            ]),
+            tuples: vec![vec![Value::Number(1), Value::String("John Doe".into())]],
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn drop_table_if_exists() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("DROP TABLE IF EXISTS users;")?;
+
+        assert_eq!(
+            db.exec("DROP TABLE users;"),
+            Err(SqlError::InvalidTable("users".into()).into())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn reuse_free_pages() -> Result<(), DbError> {
         let mut db = init_database_with(DbConf {
@@ -4116,6 +4635,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn insert_check_violation() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE products (id INT PRIMARY KEY, price INT CHECK (price >= 0));")?;
+        db.exec("INSERT INTO products(id, price) VALUES (1, 10);")?;
+
+        assert_eq!(
+            db.exec("INSERT INTO products(id, price) VALUES (2, -10);"),
+            Err(DbError::Sql(SqlError::CheckViolation(
+                Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::GtEq,
+                    right: Box::new(Expression::Value(Value::Number(0))),
+                }
+            )))
+        );
+
+        let query = db.exec("SELECT * FROM products;")?;
+        assert_eq!(query.tuples, vec![vec![
+            Value::Number(1),
+            Value::Number(10)
+        ]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_check_violation() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        db.exec("CREATE TABLE products (id INT PRIMARY KEY, price INT, discount INT, CHECK (discount <= price));")?;
+        db.exec("INSERT INTO products(id, price, discount) VALUES (1, 10, 5);")?;
+
+        assert_eq!(
+            db.exec("UPDATE products SET discount = 20;"),
+            Err(DbError::Sql(SqlError::CheckViolation(
+                Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("discount".into())),
+                    operator: BinaryOperator::LtEq,
+                    right: Box::new(Expression::Identifier("price".into())),
+                }
+            )))
+        );
+
+        let query = db.exec("SELECT * FROM products;")?;
+        assert_eq!(query.tuples, vec![vec![
+            Value::Number(1),
+            Value::Number(10),
+            Value::Number(5)
+        ]]);
+
+        Ok(())
+    }
+
     #[test]
     fn insert_duplicated_keys_auto_index() -> Result<(), DbError> {
         let mut db = init_database()?;
@@ -4189,4 +4763,25 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn parser_errors_are_rendered() -> Result<(), DbError> {
+        let mut db = init_database()?;
+
+        let error = db.exec("SELECT id,\nFROM users;").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            [
+                "error: expected identifier, number, string, '*', '-', '+' or '('. Found 'FROM' instead",
+                " --> line 2, column 1",
+                "  |",
+                "2 | FROM users;",
+                "  | ^^^^",
+            ]
+            .join("\n")
+        );
+
+        Ok(())
+    }
 }
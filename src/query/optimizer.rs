@@ -849,7 +849,10 @@ fn determine_bounds(expr: &Expression) -> (Bound<&Value>, Bound<&Value>) {
 /// Inverse of [`determine_bounds`].
 fn range_to_expr(col: &str, (start, end): (Bound<&Value>, Bound<&Value>)) -> Expression {
     // We'll use the parser to generate the expressions for us because writing
-    // every single combination manually is tedious.
+    // every single combination manually is tedious. The column goes through
+    // Display so that it gets quoted if it collides with a keyword.
+    let col = Expression::Identifier(col.into());
+
     let expr = match (start, end) {
         (Bound::Unbounded, Bound::Excluded(v)) => format!("{col} < {v}"),
         (Bound::Unbounded, Bound::Included(v)) => format!("{col} <= {v}"),
@@ -868,7 +871,7 @@ fn range_to_expr(col: &str, (start, end): (Bound<&Value>, Bound<&Value>)) -> Exp
         _ => unreachable!("can't build expr from range {:?}", (start, end)),
     };
 
-    Parser::new(&expr).parse_expression().unwrap()
+    Parser::parse_single_expression(&expr).unwrap()
 }
 
 /// Returns true if a range is an exact match like `id = 5`.
@@ -1039,7 +1042,13 @@ fn skip_col_conditions(col: &str, expr: &mut Expression) {
             }
         }
 
-        _ => match (&**left, &**right) {
+        // Only comparisons can be turned into index ranges, anything else
+        // must still be checked by the filter.
+        BinaryOperator::Eq
+        | BinaryOperator::Lt
+        | BinaryOperator::LtEq
+        | BinaryOperator::Gt
+        | BinaryOperator::GtEq => match (&**left, &**right) {
             (Expression::Identifier(ident), _) | (_, Expression::Identifier(ident))
                 if ident == col =>
             {
@@ -1048,6 +1057,8 @@ fn skip_col_conditions(col: &str, expr: &mut Expression) {
 
             _ => {}
         },
+
+        _ => {}
     }
 }
 
@@ -15,12 +15,15 @@ use crate::{
     paging,
     sql::{
         analyzer,
-        statement::{Column, DataType, Expression, Statement},
+        statement::{
+            Column, DataType, Expression, InsertSource, Select, SelectItem, Statement,
+            TableReference,
+        },
     },
     vm::{
         plan::{
-            Collect, CollectConfig, Delete, Insert, Plan, Project, Sort, SortConfig, SortKeysGen,
-            TuplesComparator, Update, Values, DEFAULT_SORT_INPUT_BUFFERS,
+            Collect, CollectConfig, Delete, Filter, Insert, Plan, Project, Sort, SortConfig,
+            SortKeysGen, TuplesComparator, Update, Values, DEFAULT_SORT_INPUT_BUFFERS,
         },
         VmDataType,
     },
@@ -34,11 +37,11 @@ pub(crate) fn generate_plan<F: Seek + Read + Write + paging::io::FileOps>(
     Ok(match statement {
         Statement::Insert {
             into,
-            columns,
-            values,
+            source: InsertSource::Values(rows),
+            ..
         } => {
             let source = Box::new(Plan::Values(Values {
-                values: VecDeque::from([values]),
+                values: VecDeque::from(rows),
             }));
 
             let table = db.table_metadata(&into)?.clone();
@@ -51,113 +54,173 @@ pub(crate) fn generate_plan<F: Seek + Read + Write + paging::io::FileOps>(
             })
         }
 
-        Statement::Select {
-            columns,
-            from,
-            r#where,
-            order_by,
-        } => {
-            let mut source = optimizer::generate_scan_plan(&from, r#where, db)?;
+        Statement::Select(select) => match *select {
+            Select {
+                columns,
+                from: None,
+                r#where,
+                ..
+            } => {
+                // There's no table to scan, so project the select list over a
+                // single empty tuple, which is dropped if WHERE is not true.
+                let mut source = Plan::Values(Values {
+                    values: VecDeque::from([vec![]]),
+                });
+
+                if let Some(filter) = r#where {
+                    source = Plan::Filter(Filter {
+                        source: Box::new(source),
+                        schema: Schema::empty(),
+                        filter,
+                    });
+                }
 
-            let page_size = db.pager.borrow().page_size;
+                let mut output_schema = Schema::empty();
+                let mut projection = Vec::with_capacity(columns.len());
 
-            let work_dir = db.work_dir.clone();
-            let table = db.table_metadata(&from)?;
+                for SelectItem { expr, alias } in columns {
+                    let name = alias.unwrap_or_else(|| expr.to_string());
+                    let data_type = resolve_unknown_type(&Schema::empty(), &expr)?;
+                    output_schema.push(Column::new(&name, data_type));
+                    projection.push(expr);
+                }
 
-            if !order_by.is_empty()
-                && order_by != [Expression::Identifier(table.schema.columns[0].name.clone())]
-            {
-                let mut sort_schema = table.schema.clone();
-                let mut sort_keys_indexes = Vec::with_capacity(order_by.len());
-
-                // Precompute all the sort keys indexes so that the sorter
-                // doesn't waste time figuring out where the columns are.
-                for expr in &order_by {
-                    let index = match expr {
-                        Expression::Identifier(col) => table.schema.index_of(col).unwrap(),
-
-                        _ => {
-                            let index = sort_schema.len();
-                            let data_type = resolve_unknown_type(&table.schema, expr)?;
-                            let col = Column::new(&format!("{expr}"), data_type);
-                            sort_schema.push(col);
-
-                            index
-                        }
-                    };
+                Plan::Project(Project {
+                    input_schema: Schema::empty(),
+                    output_schema,
+                    projection,
+                    source: Box::new(source),
+                })
+            }
 
-                    sort_keys_indexes.push(index);
-                }
+            Select {
+                columns,
+                from: Some(TableReference::Table { name: from, .. }),
+                r#where,
+                order_by,
+                ..
+            } => {
+                let mut source = optimizer::generate_scan_plan(&from, r#where, db)?;
+
+                // The analyzer only allows ascending order with nulls first,
+                // which is what the sorter does.
+                let order_by: Vec<Expression> = order_by.into_iter().map(|key| key.expr).collect();
+
+                let page_size = db.pager.borrow().page_size;
+
+                let work_dir = db.work_dir.clone();
+                let table = db.table_metadata(&from)?;
+
+                if !order_by.is_empty()
+                    && order_by != [Expression::Identifier(table.schema.columns[0].name.clone())]
+                {
+                    let mut sort_schema = table.schema.clone();
+                    let mut sort_keys_indexes = Vec::with_capacity(order_by.len());
+
+                    // Precompute all the sort keys indexes so that the sorter
+                    // doesn't waste time figuring out where the columns are.
+                    for expr in &order_by {
+                        let index = match expr {
+                            Expression::Identifier(col) => table.schema.index_of(col).unwrap(),
+
+                            _ => {
+                                let index = sort_schema.len();
+                                let data_type = resolve_unknown_type(&table.schema, expr)?;
+                                let col = Column::new(&format!("{expr}"), data_type);
+                                sort_schema.push(col);
+
+                                index
+                            }
+                        };
+
+                        sort_keys_indexes.push(index);
+                    }
 
-                // If there are no expressions that need to be evaluated for
-                // sorting then just skip the sort key generation completely,
-                // we already have all the sort keys we need.
-                let collect_source = if sort_schema.len() > table.schema.len() {
-                    Plan::SortKeysGen(SortKeysGen {
-                        source: Box::new(source),
-                        schema: table.schema.clone(),
-                        gen_exprs: order_by
-                            .into_iter()
-                            .filter(|expr| !matches!(expr, Expression::Identifier(_)))
-                            .collect(),
-                    })
-                } else {
-                    source
-                };
+                    // If there are no expressions that need to be evaluated
+                    // for sorting then just skip the sort key generation
+                    // completely, we already have all the sort keys we need.
+                    let collect_source = if sort_schema.len() > table.schema.len() {
+                        Plan::SortKeysGen(SortKeysGen {
+                            source: Box::new(source),
+                            schema: table.schema.clone(),
+                            gen_exprs: order_by
+                                .into_iter()
+                                .filter(|expr| !matches!(expr, Expression::Identifier(_)))
+                                .collect(),
+                        })
+                    } else {
+                        source
+                    };
 
-                source = Plan::Sort(Sort::from(SortConfig {
-                    page_size,
-                    work_dir: work_dir.clone(),
-                    collection: Collect::from(CollectConfig {
-                        source: Box::new(collect_source),
-                        work_dir,
-                        schema: sort_schema.clone(),
-                        mem_buf_size: page_size,
-                    }),
-                    comparator: TuplesComparator {
-                        schema: table.schema.clone(),
-                        sort_schema,
-                        sort_keys_indexes,
-                    },
-                    input_buffers: DEFAULT_SORT_INPUT_BUFFERS,
-                }));
-            }
+                    source = Plan::Sort(Sort::from(SortConfig {
+                        page_size,
+                        work_dir: work_dir.clone(),
+                        collection: Collect::from(CollectConfig {
+                            source: Box::new(collect_source),
+                            work_dir,
+                            schema: sort_schema.clone(),
+                            mem_buf_size: page_size,
+                        }),
+                        comparator: TuplesComparator {
+                            schema: table.schema.clone(),
+                            sort_schema,
+                            sort_keys_indexes,
+                        },
+                        input_buffers: DEFAULT_SORT_INPUT_BUFFERS,
+                    }));
+                }
 
-            let mut output_schema = Schema::empty();
+                let mut output_schema = Schema::empty();
+                let mut projection = Vec::with_capacity(columns.len());
 
-            for expr in &columns {
-                match expr {
-                    Expression::Identifier(ident) => output_schema
-                        .push(table.schema.columns[table.schema.index_of(ident).unwrap()].clone()),
+                for SelectItem { expr, alias } in columns {
+                    let mut col = match &expr {
+                        Expression::Identifier(ident) => {
+                            table.schema.columns[table.schema.index_of(ident).unwrap()].clone()
+                        }
 
-                    _ => {
-                        output_schema.push(Column {
-                            name: expr.to_string(), // TODO: AS alias
-                            data_type: resolve_unknown_type(&table.schema, expr)?,
+                        _ => Column {
+                            name: expr.to_string(),
+                            data_type: resolve_unknown_type(&table.schema, &expr)?,
                             constraints: vec![],
-                        });
+                        },
+                    };
+
+                    if let Some(alias) = alias {
+                        col.name = alias;
                     }
+
+                    output_schema.push(col);
+                    projection.push(expr);
+                }
+
+                // No need to project if the output schema is the exact same as
+                // the table schema.
+                if table.schema == output_schema {
+                    return Ok(source);
                 }
-            }
 
-            // No need to project if the output schema is the exact same as the
-            // table schema.
-            if table.schema == output_schema {
-                return Ok(source);
+                Plan::Project(Project {
+                    input_schema: table.schema.clone(),
+                    output_schema,
+                    projection,
+                    source: Box::new(source),
+                })
             }
 
-            Plan::Project(Project {
-                input_schema: table.schema.clone(),
-                output_schema,
-                projection: columns,
-                source: Box::new(source),
-            })
-        }
+            select => {
+                return Err(DbError::Other(format!(
+                    "statement {} not yet implemeted or supported",
+                    Statement::Select(Box::new(select))
+                )))
+            }
+        },
 
         Statement::Update {
             table,
             columns,
             r#where,
+            ..
         } => {
             let mut source = optimizer::generate_scan_plan(&table, r#where, db)?;
             let work_dir = db.work_dir.clone();
@@ -190,7 +253,11 @@ pub(crate) fn generate_plan<F: Seek + Read + Write + paging::io::FileOps>(
             })
         }
 
-        Statement::Delete { from, r#where } => {
+        Statement::Delete {
+            from: TableReference::Table { name: from, .. },
+            r#where,
+            ..
+        } => {
             let mut source = optimizer::generate_scan_plan(&from, r#where, db)?;
             let work_dir = db.work_dir.clone();
             let page_size = db.pager.borrow().page_size;
@@ -8,10 +8,13 @@
 
 use std::{collections::HashSet, fmt::Display};
 
-use super::statement::{Drop, UnaryOperator};
+use super::statement::{Direction, Drop, UnaryOperator};
 use crate::{
     db::{DatabaseContext, DbError, Schema, SqlError, TableMetadata, MKDB_META, ROW_ID_COL},
-    sql::statement::{BinaryOperator, Constraint, Create, DataType, Expression, Statement, Value},
+    sql::statement::{
+        BinaryOperator, Constraint, Create, DataType, Expression, InsertSource, Select, SelectItem,
+        Statement, TableConstraint, TableReference, Value,
+    },
     storage::tuple,
     vm::{TypeError, VmDataType},
 };
@@ -26,8 +29,6 @@ pub(crate) enum AnalyzerError {
     MissingColumns,
     /// Column specified more than once.
     DuplicatedColumn(String),
-    /// Multiple primary keys defined for the same table.
-    MultiplePrimaryKeys,
     /// Table or index already exists.
     AlreadyExists(AlreadyExists),
     /// Number of characters exceeds `VARCHAR(max)`.
@@ -59,7 +60,6 @@ impl Display for AnalyzerError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::ColumnValueCountMismatch => f.write_str("number of columns doesn't match values"),
-            Self::MultiplePrimaryKeys => f.write_str("only one primary key per table is allowed"),
             Self::MissingColumns => {
                 f.write_str("default values are not supported, all columns must be specified")
             }
@@ -83,6 +83,9 @@ impl Display for AnalyzerError {
     }
 }
 
+/// Functions that accept `DISTINCT` before their arguments.
+const AGGREGATE_FUNCTIONS: [&str; 5] = ["COUNT", "SUM", "AVG", "MIN", "MAX"];
+
 /// Analyzes the given statement and returns an error if any.
 ///
 /// If there's no error this function does nothing else.
@@ -91,12 +94,20 @@ pub(crate) fn analyze(
     ctx: &mut impl DatabaseContext,
 ) -> Result<(), DbError> {
     match statement {
-        Statement::Create(Create::Table { columns, name }) => {
+        Statement::Create(Create::Table {
+            columns,
+            name,
+            table_constraints,
+            if_not_exists,
+        }) => {
             match ctx.table_metadata(name) {
                 Err(DbError::Sql(SqlError::InvalidTable(_))) => {
                     // Table doesn't exist, we can create it.
                 }
 
+                // The statement won't do anything, there's nothing else to check.
+                Ok(_) if *if_not_exists => return Ok(()),
+
                 Ok(_) => {
                     return Err(DbError::from(AnalyzerError::AlreadyExists(
                         AlreadyExists::Table(name.clone()),
@@ -106,23 +117,65 @@ pub(crate) fn analyze(
                 Err(e) => return Err(e),
             }
 
-            let mut found_primary_key = false;
-            let mut duplicates = HashSet::new();
+            // Duplicated column names are rejected by the parser.
+            let column_names = HashSet::<&String>::from_iter(columns.iter().map(|col| &col.name));
 
             for col in columns {
-                if !duplicates.insert(&col.name) {
-                    return Err(AnalyzerError::DuplicatedColumn(col.name.to_owned()).into());
-                }
-
                 if col.name == ROW_ID_COL {
                     return Err(AnalyzerError::RowIdAssignment.into());
                 }
 
-                if col.constraints.contains(&Constraint::PrimaryKey) {
-                    if found_primary_key {
-                        return Err(AnalyzerError::MultiplePrimaryKeys.into());
+                if let DataType::Float
+                | DataType::Decimal { .. }
+                | DataType::Date
+                | DataType::Timestamp
+                | DataType::Text = col.data_type
+                {
+                    return Err(DbError::Sql(SqlError::Other(format!(
+                        "{} columns are not supported yet",
+                        col.data_type
+                    ))));
+                }
+
+                for constraint in &col.constraints {
+                    let unsupported = match constraint {
+                        Constraint::References { .. } => "REFERENCES",
+                        Constraint::AutoIncrement => "AUTO_INCREMENT",
+                        _ => continue,
+                    };
+
+                    return Err(DbError::Sql(SqlError::Other(format!(
+                        "{unsupported} constraints are not supported yet"
+                    ))));
+                }
+
+                if let Some(default) = col.default_value() {
+                    analyze_value(&Schema::empty(), col.data_type, default)?;
+                }
+
+                for constraint in &col.constraints {
+                    if let Constraint::Check(expr) = constraint {
+                        analyze_condition(&Schema::from(columns), expr)?;
+                    }
+                }
+            }
+
+            for constraint in table_constraints {
+                match constraint {
+                    // Multiple primary keys are rejected by the parser.
+                    TableConstraint::PrimaryKey(key) => {
+                        if let Some(col) = key.iter().find(|col| !column_names.contains(col)) {
+                            return Err(SqlError::InvalidColumn(col.clone()).into());
+                        }
+
+                        return Err(DbError::Sql(SqlError::Other(
+                            "composite primary keys are not supported yet".into(),
+                        )));
+                    }
+
+                    TableConstraint::Check(expr) => {
+                        analyze_condition(&Schema::from(columns), expr)?;
                     }
-                    found_primary_key = true;
                 }
             }
         }
@@ -131,7 +184,7 @@ pub(crate) fn analyze(
             table,
             unique,
             name,
-            ..
+            columns,
         }) => {
             if !unique {
                 return Err(DbError::Sql(SqlError::Other(
@@ -139,6 +192,12 @@ pub(crate) fn analyze(
                 )));
             }
 
+            if columns.len() > 1 {
+                return Err(DbError::Sql(SqlError::Other(
+                    "multi-column indexes are not supported yet".into(),
+                )));
+            }
+
             let metadata = ctx.table_metadata(table)?;
 
             // TODO: We're only checking if the table has an index with the same
@@ -154,7 +213,8 @@ pub(crate) fn analyze(
         Statement::Insert {
             into,
             columns,
-            values,
+            source,
+            returning,
         } => {
             let metadata = ctx.table_metadata(into)?;
 
@@ -162,6 +222,18 @@ pub(crate) fn analyze(
                 return Err(AnalyzerError::MkdbMetaModification.into());
             }
 
+            if !returning.is_empty() {
+                return Err(DbError::Sql(SqlError::Other(
+                    "RETURNING is not supported yet".into(),
+                )));
+            }
+
+            let InsertSource::Values(rows) = source else {
+                return Err(DbError::Sql(SqlError::Other(
+                    "INSERT ... SELECT is not supported yet".into(),
+                )));
+            };
+
             let mut columns = columns.as_slice();
 
             // In case the user didn't specify any columns.
@@ -176,7 +248,7 @@ pub(crate) fn analyze(
                 }
             }
 
-            if columns.len() != values.len() {
+            if rows.iter().any(|values| values.len() != columns.len()) {
                 return Err(AnalyzerError::ColumnValueCountMismatch.into());
             }
 
@@ -194,50 +266,148 @@ pub(crate) fn analyze(
                 }
             }
 
-            // The user can't manually set the special "row_id" the column.
-            let schema_len = if metadata.schema.columns[0].name == ROW_ID_COL {
-                metadata.schema.len() - 1
-            } else {
-                metadata.schema.len()
-            };
+            // Omitted columns are only allowed if they have a default value.
+            // The user can't manually set the special "row_id" column.
+            let missing_columns = metadata.schema.columns.iter().any(|col| {
+                col.name != ROW_ID_COL
+                    && col.default_value().is_none()
+                    && !columns.contains(&col.name)
+            });
 
-            if schema_len != columns.len() {
+            if missing_columns {
                 return Err(AnalyzerError::MissingColumns.into());
             }
 
-            for (expr, col) in values.iter().zip(columns) {
-                analyze_assignment(metadata, col, expr, false)?;
+            for values in rows {
+                for (expr, col) in values.iter().zip(columns) {
+                    analyze_assignment(metadata, col, expr, false)?;
+                }
             }
         }
 
-        Statement::Select {
-            from,
-            columns,
-            r#where,
-            order_by,
-        } => {
-            let metadata = ctx.table_metadata(from)?;
+        Statement::Select(select) => {
+            let Select {
+                ctes,
+                distinct,
+                from,
+                columns,
+                joins,
+                r#where,
+                group_by,
+                order_by,
+                limit,
+                offset,
+                ..
+            } = &**select;
+
+            if !ctes.is_empty() {
+                return Err(DbError::Sql(SqlError::Other(
+                    "common table expressions are not supported yet".into(),
+                )));
+            }
+
+            let empty = Schema::empty();
+
+            let schema = match from {
+                Some(TableReference::Table {
+                    database: Some(_), ..
+                }) => return Err(database_qualified_table()),
+
+                Some(TableReference::Table { name, .. }) => &ctx.table_metadata(name)?.schema,
+
+                Some(_) => {
+                    return Err(DbError::Sql(SqlError::Other(
+                        "subqueries are not supported yet".into(),
+                    )))
+                }
+
+                None => &empty,
+            };
+
+            if !joins.is_empty() {
+                return Err(DbError::Sql(SqlError::Other(
+                    "JOIN is not supported yet".into(),
+                )));
+            }
+
+            if !group_by.is_empty() {
+                return Err(DbError::Sql(SqlError::Other(
+                    "GROUP BY is not supported yet".into(),
+                )));
+            }
 
-            for expr in columns {
+            if *distinct {
+                return Err(DbError::Sql(SqlError::Other(
+                    "SELECT DISTINCT is not supported yet".into(),
+                )));
+            }
+
+            if limit.is_some() || offset.is_some() {
+                return Err(DbError::Sql(SqlError::Other(
+                    "LIMIT and OFFSET are not supported yet".into(),
+                )));
+            }
+
+            for SelectItem { expr, alias } in columns {
                 if expr != &Expression::Wildcard {
-                    analyze_expression(&metadata.schema, None, expr)?;
+                    analyze_expression(schema, None, expr)?;
+                } else if from.is_none() {
+                    return Err(DbError::Sql(SqlError::Other(
+                        "SELECT * requires a FROM clause".into(),
+                    )));
+                } else if alias.is_some() {
+                    return Err(DbError::Sql(SqlError::Other(
+                        "wildcards cannot have an alias".into(),
+                    )));
                 }
             }
 
-            analyze_where(&metadata.schema, r#where)?;
+            analyze_where(schema, r#where)?;
+
+            for key in order_by {
+                analyze_expression(schema, None, &key.expr)?;
+
+                if key.direction == Direction::Desc {
+                    return Err(DbError::Sql(SqlError::Other(
+                        "ORDER BY ... DESC is not supported yet".into(),
+                    )));
+                }
 
-            for expr in order_by {
-                analyze_expression(&metadata.schema, None, expr)?;
+                if !key.nulls_first() {
+                    return Err(DbError::Sql(SqlError::Other(
+                        "NULLS LAST is not supported yet".into(),
+                    )));
+                }
             }
         }
 
-        Statement::Delete { from, r#where } => {
-            let metadata = ctx.table_metadata(from)?;
+        Statement::Delete {
+            from,
+            r#where,
+            returning,
+        } => {
+            let TableReference::Table { database, name, .. } = from else {
+                return Err(DbError::Sql(SqlError::Other(
+                    "subqueries are not supported yet".into(),
+                )));
+            };
 
-            if from == MKDB_META {
+            if database.is_some() {
+                return Err(database_qualified_table());
+            }
+
+            let metadata = ctx.table_metadata(name)?;
+
+            if name == MKDB_META {
                 return Err(AnalyzerError::MkdbMetaModification.into());
             }
 
+            if !returning.is_empty() {
+                return Err(DbError::Sql(SqlError::Other(
+                    "RETURNING is not supported yet".into(),
+                )));
+            }
+
             analyze_where(&metadata.schema, r#where)?;
         }
 
@@ -245,6 +415,7 @@ pub(crate) fn analyze(
             table,
             columns,
             r#where,
+            returning,
         } => {
             let metadata = ctx.table_metadata(table)?;
 
@@ -252,6 +423,12 @@ pub(crate) fn analyze(
                 return Err(AnalyzerError::MkdbMetaModification.into());
             }
 
+            if !returning.is_empty() {
+                return Err(DbError::Sql(SqlError::Other(
+                    "RETURNING is not supported yet".into(),
+                )));
+            }
+
             for col in columns {
                 analyze_assignment(metadata, &col.identifier, &col.value, true)?;
             }
@@ -263,8 +440,74 @@ pub(crate) fn analyze(
             analyze(inner, ctx)?;
         }
 
-        Statement::Drop(Drop::Table(table)) => {
+        Statement::Drop(Drop::Table { name, if_exists }) => match ctx.table_metadata(name) {
+            Err(DbError::Sql(SqlError::InvalidTable(_))) if *if_exists => {}
+            Err(e) => return Err(e),
+            Ok(_) => {}
+        },
+
+        Statement::SetOperation { op, .. } => {
+            return Err(DbError::Sql(SqlError::Other(format!(
+                "{op} is not supported yet"
+            ))));
+        }
+
+        Statement::Drop(Drop::Index(_)) => {
+            return Err(DbError::Sql(SqlError::Other(
+                "DROP INDEX is not supported yet".into(),
+            )));
+        }
+
+        Statement::ShowTables => {
+            return Err(DbError::Sql(SqlError::Other(
+                "SHOW TABLES is not supported yet".into(),
+            )));
+        }
+
+        Statement::ShowColumns { table } => {
+            ctx.table_metadata(table)?;
+
+            return Err(DbError::Sql(SqlError::Other(
+                "SHOW COLUMNS is not supported yet".into(),
+            )));
+        }
+
+        Statement::Describe(table) => {
             ctx.table_metadata(table)?;
+
+            return Err(DbError::Sql(SqlError::Other(
+                "DESCRIBE is not supported yet".into(),
+            )));
+        }
+
+        Statement::Use(_) => {
+            return Err(DbError::Sql(SqlError::Other(
+                "USE is not supported yet".into(),
+            )));
+        }
+
+        Statement::Create(Create::View { .. }) | Statement::Drop(Drop::View(_)) => {
+            return Err(DbError::Sql(SqlError::Other(
+                "views are not supported yet".into(),
+            )));
+        }
+
+        Statement::Savepoint(_) | Statement::RollbackTo(_) => {
+            return Err(DbError::Sql(SqlError::Other(
+                "savepoints are not supported yet".into(),
+            )));
+        }
+
+        Statement::Truncate(table) => {
+            ctx.table_metadata(table)?;
+
+            if table == MKDB_META {
+                return Err(AnalyzerError::MkdbMetaModification.into());
+            }
+
+            return Err(DbError::Sql(SqlError::Other(
+                "TRUNCATE is not supported yet".into(),
+            )));
         }
 
         _ => {
@@ -275,12 +518,25 @@ pub(crate) fn analyze(
     Ok(())
 }
 
-/// Makes sure that the given expression is valid and evaluates to a boolean.
+/// Error returned for table references like `db.table`, since we can only
+/// access the current database for now.
+fn database_qualified_table() -> DbError {
+    DbError::Sql(SqlError::Other(
+        "database-qualified table names are not supported yet".into(),
+    ))
+}
+
+/// Same as [`analyze_condition`] but for optional `WHERE` clauses.
 fn analyze_where(schema: &Schema, r#where: &Option<Expression>) -> Result<(), DbError> {
     let Some(expr) = r#where else {
         return Ok(());
     };
 
+    analyze_condition(schema, expr)
+}
+
+/// Makes sure that the given expression is valid and evaluates to a boolean.
+fn analyze_condition(schema: &Schema, expr: &Expression) -> Result<(), DbError> {
     if let VmDataType::Bool = analyze_expression(schema, None, expr)? {
         return Ok(());
     };
@@ -292,9 +548,7 @@ fn analyze_where(schema: &Schema, r#where: &Option<Expression>) -> Result<(), Db
 }
 
 /// Makes sure that the expression will evaluate to a data type that can be
-/// assigned to the given column.
-///
-/// Performs some additional checks such as VARCHAR(max) character limits.
+/// assigned to the given column. See [`analyze_value`].
 fn analyze_assignment(
     table: &TableMetadata,
     column: &str,
@@ -312,12 +566,20 @@ fn analyze_assignment(
 
     let data_type = table.schema.columns[index].data_type;
 
-    let expected_data_type = VmDataType::from(data_type);
-    let pre_eval_data_type = if allow_identifiers {
-        analyze_expression(&table.schema, Some(&data_type), value)?
+    if allow_identifiers {
+        analyze_value(&table.schema, data_type, value)
     } else {
-        analyze_expression(&Schema::empty(), Some(&data_type), value)?
-    };
+        analyze_value(&Schema::empty(), data_type, value)
+    }
+}
+
+/// Makes sure that the given expression can be stored in a column of type
+/// `data_type`.
+///
+/// Performs some additional checks such as VARCHAR(max) character limits.
+fn analyze_value(schema: &Schema, data_type: DataType, value: &Expression) -> Result<(), SqlError> {
+    let expected_data_type = VmDataType::from(data_type);
+    let pre_eval_data_type = analyze_expression(schema, Some(&data_type), value)?;
 
     if expected_data_type != pre_eval_data_type {
         return Err(SqlError::TypeError(TypeError::ExpectedType {
@@ -362,6 +624,10 @@ pub(crate) fn analyze_expression(
 
                 VmDataType::Number
             }
+            Value::Float(_) => {
+                return Err(SqlError::Other("FLOAT values are not supported yet".into()))
+            }
+            Value::Null => return Err(SqlError::Other("NULL values are not supported yet".into())),
         },
 
         Expression::Identifier(ident) => {
@@ -385,8 +651,17 @@ pub(crate) fn analyze_expression(
                 return Ok(VmDataType::Number);
             }
 
-            match analyze_expression(schema, col_data_type, expr)? {
-                VmDataType::Number => VmDataType::Number,
+            match (operator, analyze_expression(schema, col_data_type, expr)?) {
+                (UnaryOperator::Plus | UnaryOperator::Minus, VmDataType::Number) => {
+                    VmDataType::Number
+                }
+
+                (UnaryOperator::Not, VmDataType::Bool) => VmDataType::Bool,
+
+                (UnaryOperator::Not, _) => Err(TypeError::ExpectedType {
+                    expected: VmDataType::Bool,
+                    found: *expr.clone(),
+                })?,
 
                 _ => Err(TypeError::ExpectedType {
                     expected: VmDataType::Number,
@@ -408,9 +683,9 @@ pub(crate) fn analyze_expression(
             // for errors.
             let mismatched_types = || {
                 SqlError::TypeError(TypeError::CannotApplyBinary {
-                    left: *left.clone(),
+                    left: left.clone(),
                     operator: *operator,
-                    right: *right.clone(),
+                    right: right.clone(),
                 })
             };
 
@@ -433,7 +708,13 @@ pub(crate) fn analyze_expression(
                 BinaryOperator::Plus
                 | BinaryOperator::Minus
                 | BinaryOperator::Div
+                | BinaryOperator::Mod
                 | BinaryOperator::Mul
+                | BinaryOperator::BitAnd
+                | BinaryOperator::BitOr
+                | BinaryOperator::BitXor
+                | BinaryOperator::ShiftLeft
+                | BinaryOperator::ShiftRight
                     if left_data_type == VmDataType::Number =>
                 {
                     VmDataType::Number
@@ -445,20 +726,144 @@ pub(crate) fn analyze_expression(
 
         Expression::Nested(expr) => analyze_expression(schema, col_data_type, expr)?,
 
+        Expression::InList { expr, list, .. } => {
+            let data_type = analyze_expression(schema, col_data_type, expr)?;
+
+            for item in list {
+                if analyze_expression(schema, col_data_type, item)? != data_type {
+                    return Err(TypeError::ExpectedType {
+                        expected: data_type,
+                        found: item.clone(),
+                    })?;
+                }
+            }
+
+            VmDataType::Bool
+        }
+
+        Expression::Between {
+            expr, low, high, ..
+        } => {
+            let data_type = analyze_expression(schema, col_data_type, expr)?;
+
+            for bound in [low, high] {
+                if analyze_expression(schema, col_data_type, bound)? != data_type {
+                    return Err(TypeError::ExpectedType {
+                        expected: data_type,
+                        found: *bound.clone(),
+                    })?;
+                }
+            }
+
+            VmDataType::Bool
+        }
+
+        Expression::IsNull { expr, .. } => {
+            analyze_expression(schema, col_data_type, expr)?;
+            VmDataType::Bool
+        }
+
+        Expression::Like { expr, pattern, .. } => {
+            for operand in [expr, pattern] {
+                if analyze_expression(schema, col_data_type, operand)? != VmDataType::String {
+                    return Err(TypeError::ExpectedType {
+                        expected: VmDataType::String,
+                        found: *operand.clone(),
+                    })?;
+                }
+            }
+
+            VmDataType::Bool
+        }
+
+        Expression::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            // Searched CASE expressions need boolean conditions, simple CASE
+            // expressions compare the operand against every WHEN value.
+            let condition_data_type = match operand {
+                Some(operand) => analyze_expression(schema, col_data_type, operand)?,
+                None => VmDataType::Bool,
+            };
+
+            let result_data_type = analyze_expression(schema, col_data_type, &when_then[0].1)?;
+
+            for (condition, result) in when_then {
+                for (expr, expected) in
+                    [(condition, condition_data_type), (result, result_data_type)]
+                {
+                    if analyze_expression(schema, col_data_type, expr)? != expected {
+                        return Err(TypeError::ExpectedType {
+                            expected,
+                            found: expr.clone(),
+                        })?;
+                    }
+                }
+            }
+
+            if let Some(else_result) = else_result {
+                if analyze_expression(schema, col_data_type, else_result)? != result_data_type {
+                    return Err(TypeError::ExpectedType {
+                        expected: result_data_type,
+                        found: *else_result.clone(),
+                    })?;
+                }
+            }
+
+            result_data_type
+        }
+
+        Expression::Function {
+            name,
+            distinct: true,
+            ..
+        } if !AGGREGATE_FUNCTIONS.contains(&name.to_uppercase().as_str()) => {
+            return Err(SqlError::Other(format!(
+                "DISTINCT is only allowed in aggregate functions, {name}() is not one of them"
+            )))
+        }
+
+        Expression::Function { name, .. } => {
+            return Err(SqlError::Other(format!(
+                "function {name}() is not supported yet"
+            )))
+        }
+
         Expression::Wildcard => {
             return Err(SqlError::Other("unexpected wildcard expression (*)".into()))
         }
+
+        Expression::Cast { .. } => return Err(SqlError::Other("CAST is not supported yet".into())),
+
+        Expression::Row(_) => {
+            return Err(SqlError::Other("row values are not supported yet".into()))
+        }
+
+        Expression::Parameter(_) | Expression::NamedParameter(_) => {
+            return Err(SqlError::Other("parameters are not supported yet".into()))
+        }
+
+        Expression::Subquery(_)
+        | Expression::Exists { .. }
+        | Expression::InSubquery { .. }
+        | Expression::Quantified { .. } => {
+            return Err(SqlError::Other("subqueries are not supported yet".into()))
+        }
+
+        Expression::QualifiedIdentifier { .. } | Expression::QualifiedWildcard(_) => {
+            return Err(SqlError::Other(
+                "qualified identifiers are not supported yet".into(),
+            ))
+        }
     })
 }
 
 /// Returns an error if the integer is out of range for the given data type.
 fn analyze_integer_range(integer: &i128, data_type: &DataType) -> Result<(), AnalyzerError> {
-    if let DataType::BigInt | DataType::Int | DataType::UnsignedBigInt | DataType::UnsignedInt =
-        data_type
-    {
-        if !tuple::integer_is_within_range(integer, data_type) {
-            return Err(AnalyzerError::IntegerOutOfRange(*integer, *data_type));
-        }
+    if data_type.is_integer() && !tuple::integer_is_within_range(integer, data_type) {
+        return Err(AnalyzerError::IntegerOutOfRange(*integer, *data_type));
     }
 
     Ok(())
@@ -471,7 +876,7 @@ mod tests {
         db::{Context, DbError, SqlError},
         sql::{
             analyzer::analyze,
-            parser::Parser,
+            parser::{ErrorKind, Parser, ParserError},
             statement::{BinaryOperator, DataType, Expression, Value},
         },
         vm::{TypeError, VmDataType},
@@ -492,6 +897,24 @@ mod tests {
         Ok(())
     }
 
+    /// Some invalid statements never reach the analyzer because the parser
+    /// already knows enough to reject them.
+    fn assert_rejected_by_parser(sql: &str, message: &str) {
+        let result = assert_analyze(Analyze {
+            ctx: &[],
+            sql,
+            expected: Ok(()),
+        });
+
+        assert!(
+            matches!(
+                &result,
+                Err(DbError::Parser(ParserError { kind: ErrorKind::Other(m), .. })) if m == message
+            ),
+            "{result:?}"
+        );
+    }
+
     #[test]
     fn select_from_invalid_table() -> Result<(), DbError> {
         assert_analyze(Analyze {
@@ -502,23 +925,420 @@ mod tests {
     }
 
     #[test]
-    fn insert_into_invalid_table() -> Result<(), DbError> {
+    fn float_column_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "CREATE TABLE products (id INT PRIMARY KEY, price FLOAT);",
+            expected: Err(SqlError::Other("FLOAT columns are not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn date_column_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "CREATE TABLE events (id INT PRIMARY KEY, created_at TIMESTAMP);",
+            expected: Err(SqlError::Other("TIMESTAMP columns are not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn references_not_supported() -> Result<(), DbError> {
         assert_analyze(Analyze {
             ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
-            sql: "INSERT INTO tasks (id, title) VALUES (1, 'Test');",
-            expected: Err(SqlError::InvalidTable("tasks".into()).into()),
+            sql: "CREATE TABLE orders (id INT PRIMARY KEY, user_id INT REFERENCES users(id));",
+            expected: Err(
+                SqlError::Other("REFERENCES constraints are not supported yet".into()).into(),
+            ),
+        })
+    }
+
+    #[test]
+    fn composite_primary_key_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "CREATE TABLE follows (follower INT, followed INT, PRIMARY KEY (follower, followed));",
+            expected: Err(
+                SqlError::Other("composite primary keys are not supported yet".into()).into(),
+            ),
+        })
+    }
+
+    #[test]
+    fn multiple_primary_keys_with_composite_key() {
+        assert_rejected_by_parser(
+            "CREATE TABLE follows (id INT PRIMARY KEY, a INT, b INT, PRIMARY KEY (a, b));",
+            "only one primary key per table is allowed",
+        );
+    }
+
+    #[test]
+    fn multi_column_index_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, first_name VARCHAR(255), last_name VARCHAR(255));"],
+            sql: "CREATE UNIQUE INDEX name_uq_idx ON users(first_name, last_name);",
+            expected: Err(SqlError::Other("multi-column indexes are not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn drop_index_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, email VARCHAR(255) UNIQUE);"],
+            sql: "DROP INDEX users_email_uq_index;",
+            expected: Err(SqlError::Other("DROP INDEX is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn show_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SHOW TABLES;",
+            expected: Err(SqlError::Other("SHOW TABLES is not supported yet".into()).into()),
+        })?;
+
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SHOW COLUMNS FROM users;",
+            expected: Err(SqlError::Other("SHOW COLUMNS is not supported yet".into()).into()),
+        })?;
+
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "SHOW COLUMNS FROM users;",
+            expected: Err(SqlError::InvalidTable("users".into()).into()),
+        })
+    }
+
+    #[test]
+    fn describe_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "DESC users;",
+            expected: Err(SqlError::Other("DESCRIBE is not supported yet".into()).into()),
+        })?;
+
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "DESCRIBE users;",
+            expected: Err(SqlError::InvalidTable("users".into()).into()),
+        })
+    }
+
+    #[test]
+    fn common_table_expressions_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, age INT);"],
+            sql: "WITH adults AS (SELECT id FROM users WHERE age >= 18) SELECT * FROM adults;",
+            expected: Err(
+                SqlError::Other("common table expressions are not supported yet".into()).into(),
+            ),
+        })
+    }
+
+    #[test]
+    fn database_qualified_table_not_supported() -> Result<(), DbError> {
+        for sql in ["SELECT * FROM test.users;", "DELETE FROM test.users;"] {
+            assert_analyze(Analyze {
+                ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+                sql,
+                expected: Err(SqlError::Other(
+                    "database-qualified table names are not supported yet".into(),
+                )
+                .into()),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn use_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "USE test;",
+            expected: Err(SqlError::Other("USE is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn views_not_supported() -> Result<(), DbError> {
+        for sql in ["CREATE VIEW ids AS SELECT id FROM users;", "DROP VIEW ids;"] {
+            assert_analyze(Analyze {
+                ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+                sql,
+                expected: Err(SqlError::Other("views are not supported yet".into()).into()),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn distinct_in_non_aggregate_function() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255));"],
+            sql: "SELECT UPPER(DISTINCT name) FROM users;",
+            expected: Err(SqlError::Other(
+                "DISTINCT is only allowed in aggregate functions, UPPER() is not one of them"
+                    .into(),
+            )
+            .into()),
+        })
+    }
+
+    #[test]
+    fn distinct_in_aggregate_function() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255));"],
+            sql: "SELECT count(DISTINCT name) FROM users;",
+            expected: Err(SqlError::Other("function count() is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn order_by_desc_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, age INT);"],
+            sql: "SELECT * FROM users ORDER BY age DESC;",
+            expected: Err(SqlError::Other("ORDER BY ... DESC is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn order_by_nulls_last_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, age INT);"],
+            sql: "SELECT * FROM users ORDER BY age ASC NULLS LAST;",
+            expected: Err(SqlError::Other("NULLS LAST is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn order_by_asc_nulls_first() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, age INT);"],
+            sql: "SELECT * FROM users ORDER BY age ASC NULLS FIRST, id;",
+            expected: Ok(()),
+        })
+    }
+
+    #[test]
+    fn select_wildcard_with_alias() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SELECT * AS everything FROM users;",
+            expected: Err(SqlError::Other("wildcards cannot have an alias".into()).into()),
+        })
+    }
+
+    #[test]
+    fn select_qualified_identifier_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SELECT u.id FROM users u;",
+            expected: Err(
+                SqlError::Other("qualified identifiers are not supported yet".into()).into(),
+            ),
+        })
+    }
+
+    #[test]
+    fn select_join_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[
+                "CREATE TABLE users (id INT PRIMARY KEY);",
+                "CREATE TABLE orders (id INT PRIMARY KEY, user_id INT);",
+            ],
+            sql: "SELECT * FROM users JOIN orders ON users.id = orders.user_id;",
+            expected: Err(SqlError::Other("JOIN is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn subquery_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE products (id INT PRIMARY KEY, price INT);"],
+            sql: "SELECT * FROM products WHERE price > (SELECT price FROM products WHERE id = 1);",
+            expected: Err(SqlError::Other("subqueries are not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn derived_table_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SELECT * FROM (SELECT id FROM users) AS sub;",
+            expected: Err(SqlError::Other("subqueries are not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn union_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SELECT id FROM users UNION SELECT id FROM users;",
+            expected: Err(SqlError::Other("UNION is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn insert_select_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[
+                "CREATE TABLE users (id INT PRIMARY KEY);",
+                "CREATE TABLE admins (id INT PRIMARY KEY);",
+            ],
+            sql: "INSERT INTO admins (id) SELECT id FROM users;",
+            expected: Err(SqlError::Other("INSERT ... SELECT is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn truncate_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "TRUNCATE TABLE users;",
+            expected: Err(SqlError::Other("TRUNCATE is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn truncate_invalid_table() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "TRUNCATE TABLE users;",
+            expected: Err(SqlError::InvalidTable("users".into()).into()),
+        })
+    }
+
+    #[test]
+    fn savepoints_not_supported() -> Result<(), DbError> {
+        for sql in ["SAVEPOINT sp;", "ROLLBACK TO SAVEPOINT sp;"] {
+            assert_analyze(Analyze {
+                ctx: &[],
+                sql,
+                expected: Err(SqlError::Other("savepoints are not supported yet".into()).into()),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn case_results_with_different_types() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, age INT);"],
+            sql: "SELECT CASE WHEN age > 18 THEN 'adult' ELSE 0 END FROM users;",
+            expected: Err(TypeError::ExpectedType {
+                expected: VmDataType::String,
+                found: Expression::Value(Value::Number(0)),
+            }
+            .into()),
+        })
+    }
+
+    #[test]
+    fn case_condition_not_boolean() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, age INT);"],
+            sql: "SELECT CASE WHEN age THEN 1 END FROM users;",
+            expected: Err(TypeError::ExpectedType {
+                expected: VmDataType::Bool,
+                found: Expression::Identifier("age".into()),
+            }
+            .into()),
+        })
+    }
+
+    #[test]
+    fn cast_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE products (id INT PRIMARY KEY, price INT);"],
+            sql: "SELECT CAST(price AS VARCHAR(10)) FROM products;",
+            expected: Err(SqlError::Other("CAST is not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn row_values_not_supported() -> Result<(), DbError> {
+        for sql in [
+            "SELECT * FROM users WHERE (id, age) = (1, 20);",
+            "SELECT * FROM users WHERE (id, age) IN ((1, 20), (2, 30));",
+        ] {
+            assert_analyze(Analyze {
+                ctx: &["CREATE TABLE users (id INT PRIMARY KEY, age INT);"],
+                sql,
+                expected: Err(SqlError::Other("row values are not supported yet".into()).into()),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parameters_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SELECT * FROM users WHERE id = ?;",
+            expected: Err(SqlError::Other("parameters are not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn select_limit_not_supported() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "SELECT * FROM users LIMIT 10 OFFSET 5;",
+            expected: Err(SqlError::Other("LIMIT and OFFSET are not supported yet".into()).into()),
+        })
+    }
+
+    #[test]
+    fn select_without_from() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "SELECT 1 + 1, 'hello' AS greeting;",
+            expected: Ok(()),
+        })
+    }
+
+    #[test]
+    fn select_wildcard_without_from() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "SELECT *;",
+            expected: Err(SqlError::Other("SELECT * requires a FROM clause".into()).into()),
         })
     }
 
     #[test]
-    fn multiple_primary_keys() -> Result<(), DbError> {
+    fn select_column_without_from() -> Result<(), DbError> {
         assert_analyze(Analyze {
             ctx: &[],
-            sql: "CREATE TABLE users (id INT PRIMARY KEY, email VARCHAR(255) PRIMARY KEY);",
-            expected: Err(AnalyzerError::MultiplePrimaryKeys.into()),
+            sql: "SELECT id;",
+            expected: Err(SqlError::InvalidColumn("id".into()).into()),
+        })
+    }
+
+    #[test]
+    fn insert_into_invalid_table() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY);"],
+            sql: "INSERT INTO tasks (id, title) VALUES (1, 'Test');",
+            expected: Err(SqlError::InvalidTable("tasks".into()).into()),
         })
     }
 
+    #[test]
+    fn multiple_primary_keys() {
+        assert_rejected_by_parser(
+            "CREATE TABLE users (id INT PRIMARY KEY, email VARCHAR(255) PRIMARY KEY);",
+            "only one primary key per table is allowed",
+        );
+    }
+
     #[test]
     fn insert_count_mismatch() -> Result<(), DbError> {
         assert_analyze(Analyze {
@@ -569,15 +1389,62 @@ mod tests {
         })
     }
 
+    #[test]
+    fn insert_omitting_columns_with_default_values() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255) DEFAULT 'unknown');"],
+            sql: "INSERT INTO users (id) VALUES (1);",
+            expected: Ok(()),
+        })
+    }
+
+    #[test]
+    fn create_table_default_wrong_data_type() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "CREATE TABLE users (id INT PRIMARY KEY, age INT DEFAULT 'old');",
+            expected: Err(DbError::from(TypeError::ExpectedType {
+                expected: VmDataType::Number,
+                found: Expression::Value(Value::String("old".into())),
+            })),
+        })
+    }
+
+    #[test]
+    fn create_table_check_not_boolean() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "CREATE TABLE products (id INT PRIMARY KEY, price INT CHECK (price + 1));",
+            expected: Err(DbError::from(TypeError::ExpectedType {
+                expected: VmDataType::Bool,
+                found: Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Plus,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                },
+            })),
+        })
+    }
+
+    #[test]
+    fn create_table_with_table_check_unknown_column() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql:
+                "CREATE TABLE products (id INT PRIMARY KEY, price INT, CHECK (discount <= price));",
+            expected: Err(SqlError::InvalidColumn("discount".into()).into()),
+        })
+    }
+
     #[test]
     fn select_where_invalid_expression() -> Result<(), DbError> {
         assert_analyze(Analyze {
             ctx: &["CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255), email VARCHAR(255) UNIQUE);"],
             sql: "SELECT * FROM users WHERE id = 'string';",
             expected: Err(DbError::from(TypeError::CannotApplyBinary {
-                left: Expression::Identifier("id".into()),
+                left: Box::new(Expression::Identifier("id".into())),
                 operator: BinaryOperator::Eq,
-                right: Expression::Value(Value::String("string".into()))
+                right: Box::new(Expression::Value(Value::String("string".into()))),
             })),
         })
     }
@@ -610,6 +1477,25 @@ mod tests {
         })
     }
 
+    #[test]
+    fn returning_not_supported() -> Result<(), DbError> {
+        let ctx = &["CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255));"];
+
+        for sql in [
+            "INSERT INTO users (id, name) VALUES (1, 'John') RETURNING *;",
+            "UPDATE users SET name = 'Jane' RETURNING id;",
+            "DELETE FROM users WHERE id = 1 RETURNING id, name;",
+        ] {
+            assert_analyze(Analyze {
+                ctx,
+                sql,
+                expected: Err(SqlError::Other("RETURNING is not supported yet".into()).into()),
+            })?;
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn table_already_exists() -> Result<(), DbError> {
         assert_analyze(Analyze {
@@ -619,6 +1505,24 @@ mod tests {
         })
     }
 
+    #[test]
+    fn create_table_if_not_exists() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255));"],
+            sql: "CREATE TABLE IF NOT EXISTS users (id INT PRIMARY KEY, name VARCHAR(255));",
+            expected: Ok(()),
+        })
+    }
+
+    #[test]
+    fn drop_table_if_exists() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &[],
+            sql: "DROP TABLE IF EXISTS users;",
+            expected: Ok(()),
+        })
+    }
+
     #[test]
     fn row_id_assignment_on_insert() -> Result<(), DbError> {
         assert_analyze(Analyze {
@@ -662,4 +1566,16 @@ mod tests {
             ))),
         })
     }
+
+    #[test]
+    fn small_integer_out_of_range() -> Result<(), DbError> {
+        assert_analyze(Analyze {
+            ctx: &["CREATE TABLE stats (id INT PRIMARY KEY, count SMALLINT);"],
+            sql: "INSERT INTO stats (id, count) VALUES (1, -32769);",
+            expected: Err(DbError::from(AnalyzerError::IntegerOutOfRange(
+                -32769,
+                DataType::SmallInt,
+            ))),
+        })
+    }
 }
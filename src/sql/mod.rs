@@ -8,18 +8,36 @@ pub(crate) mod optimizer;
 pub(crate) mod parser;
 pub(crate) mod prepare;
 pub(crate) mod statement;
+#[cfg(test)]
+pub(crate) mod visitor;
 
 use self::{
     analyzer::analyze, optimizer::optimize, parser::Parser, prepare::prepare, statement::Statement,
 };
-use crate::db::{DatabaseContext, DbError};
+use crate::db::{DatabaseContext, DbError, SqlError};
 
 /// Passes the given text input through all the SQL pipeline stages.
 ///
 /// Then end result is a [`Statement`] instance ready to go through the query
 /// plan generation final stage.
 pub(crate) fn pipeline(input: &str, db: &mut impl DatabaseContext) -> Result<Statement, DbError> {
-    let mut statement = Parser::new(input).parse_statement()?;
+    let mut statements = Parser::new(input)
+        .allow_missing_final_semicolon(true)
+        .statements();
+
+    let Some(statement) = statements.next() else {
+        return Err(SqlError::Other(String::from("nothing to execute")).into());
+    };
+
+    let mut statement = statement?;
+
+    // Statements are parsed lazily, so we stop at the second one without
+    // looking at the rest of the input.
+    if statements.next().is_some() {
+        return Err(
+            SqlError::Other(String::from("only one statement can be executed at a time")).into(),
+        );
+    }
 
     analyze(&statement, db)?;
     optimize(&mut statement)?;
@@ -5,25 +5,34 @@
 
 use std::mem;
 
-use super::statement::{BinaryOperator, Expression, Statement, UnaryOperator, Value};
+use super::statement::{
+    BinaryOperator, Expression, InsertSource, Select, Statement, UnaryOperator, Value,
+};
 use crate::{db::SqlError, vm};
 
 /// Takes a statement and transforms it into an equivalent, optimized one.
 pub(crate) fn optimize(statement: &mut Statement) -> Result<(), SqlError> {
     match statement {
-        Statement::Insert { values, .. } => {
-            simplify_all(values.iter_mut())?;
-        }
-
-        Statement::Select {
-            columns,
-            r#where,
-            order_by,
+        Statement::Insert {
+            source: InsertSource::Values(rows),
             ..
         } => {
-            simplify_all(columns.iter_mut())?;
+            for values in rows {
+                simplify_all(values.iter_mut())?;
+            }
+        }
+
+        Statement::Select(select) => {
+            let Select {
+                columns,
+                r#where,
+                order_by,
+                ..
+            } = &mut **select;
+
+            simplify_all(columns.iter_mut().map(|item| &mut item.expr))?;
             simplfy_where(r#where)?;
-            simplify_all(order_by.iter_mut())?;
+            simplify_all(order_by.iter_mut().map(|key| &mut key.expr))?;
         }
 
         Statement::Delete { r#where, .. } => simplfy_where(r#where)?,
@@ -238,12 +247,43 @@ pub(crate) fn simplify(expression: &mut Expression) -> Result<(), SqlError> {
             *expression = mem::replace(nested.as_mut(), Expression::Wildcard);
         }
 
-        _other => {}
+        // Function arguments, IN lists, BETWEEN bounds and so on can still
+        // contain literal operations, but we don't apply the rules above to
+        // them because they're not used as often.
+        other => *other = fold_constants(mem::replace(other, Expression::Wildcard)),
     };
 
     Ok(())
 }
 
+/// Folds every [`Expression::BinaryOperation`] whose operands are both
+/// literal values into a single [`Expression::Value`], so `10 + 2 * 20`
+/// becomes `50`.
+///
+/// Unlike [`simplify`] this never fails. Operations that can't be computed,
+/// like `1 / 0`, are left as they are and will fail at runtime instead.
+/// Parenthesis around literals are dropped so that folding can continue in
+/// the parent node: `(2 + 3) * x` becomes `5 * x`.
+pub(crate) fn fold_constants(expression: Expression) -> Expression {
+    expression.map(&mut |expr| match expr {
+        Expression::Nested(nested) if matches!(*nested, Expression::Value(_)) => *nested,
+
+        Expression::BinaryOperation {
+            ref left,
+            ref right,
+            ..
+        } if matches!(
+            (&**left, &**right),
+            (Expression::Value(_), Expression::Value(_))
+        ) =>
+        {
+            resolve_literal_expression(&expr).unwrap_or(expr)
+        }
+
+        other => other,
+    })
+}
+
 /// Resolves an expression that doesn't contain variables into [`Expression::Value`].
 ///
 /// This function is the only reason we need to return [`Result`] in this
@@ -257,7 +297,7 @@ fn resolve_literal_expression(expression: &Expression) -> Result<Expression, Sql
 
 #[cfg(test)]
 mod tests {
-    use super::{optimize, simplify};
+    use super::{fold_constants, optimize, simplify};
     use crate::{
         db::DbError,
         sql::{
@@ -438,4 +478,69 @@ mod tests {
             optimized: "DELETE FROM t WHERE x >= 0 AND x != 20;",
         })
     }
+
+    #[test]
+    fn optimize_fold_constants_in_other_expressions() -> Result<(), DbError> {
+        assert_optimize_sql(Opt {
+            raw_input:
+                "SELECT * FROM t WHERE x IN (1 + 1, 2 * 3) AND y BETWEEN 10 - 5 AND (2 + 3) * 2;",
+            optimized: "SELECT * FROM t WHERE x IN (2, 6) AND y BETWEEN 5 AND 10;",
+        })
+    }
+
+    #[test]
+    fn optimize_fold_constants_in_case_and_functions() -> Result<(), DbError> {
+        assert_optimize_sql(Opt {
+            raw_input: "SELECT CASE WHEN x > 1 + 1 THEN 2 * 2 ELSE 0 END, ABS(20 - 10) FROM t;",
+            optimized: "SELECT CASE WHEN x > 2 THEN 4 ELSE 0 END, ABS(10) FROM t;",
+        })
+    }
+
+    fn fold_expr(expr: &str) -> Result<Expression, DbError> {
+        Ok(fold_constants(Parser::new(expr).parse_expression()?))
+    }
+
+    #[test]
+    fn fold_arithmetic() -> Result<(), DbError> {
+        assert_eq!(
+            fold_expr("10 + 2 * 20")?,
+            Expression::Value(Value::Number(50))
+        );
+        assert_eq!(
+            fold_expr("(1 + 2) * (8 - 4) / 3")?,
+            Expression::Value(Value::Number(4))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fold_subtrees_without_identifiers() -> Result<(), DbError> {
+        assert_eq!(
+            fold_expr("x + 2 * 20 > (3 - 1) * y")?,
+            Parser::new("x + 40 > 2 * y").parse_expression()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dont_fold_identifiers() -> Result<(), DbError> {
+        assert_eq!(
+            fold_expr("x + 2 + 4")?,
+            Parser::new("x + 2 + 4").parse_expression()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dont_fold_division_by_zero() -> Result<(), DbError> {
+        assert_eq!(
+            fold_expr("1 + 10 / (5 - 5)")?,
+            Parser::new("1 + 10 / 0").parse_expression()?
+        );
+
+        Ok(())
+    }
 }
@@ -1,19 +1,49 @@
 //! SQL parsers. Produces [`Statement`] variants from UTF-8 strings.
 
 use core::iter::Peekable;
-use std::fmt::Display;
+use std::{error::Error, fmt::Display, str::FromStr};
 
 use super::{
     statement::{
-        Assignment, BinaryOperator, Column, Constraint, Create, DataType, Drop, Expression,
-        Statement, UnaryOperator, Value,
+        AlterAction, Assignment, BinaryOperator, Column, Constraint, Create, Cte, DataType,
+        Direction, Drop, Expression, InsertSource, Join, JoinType, NullsOrder, OrderBy, Quantifier,
+        Select, SelectItem, SetOp, Statement, TableConstraint, TableReference, UnaryOperator,
+        Value,
     },
     token::{Keyword, Token},
     tokenizer::{self, Location, TokenWithLocation, Tokenizer, TokenizerError},
 };
 
 /// See [`Parser::get_next_precedence`] for details.
-const UNARY_ARITHMETIC_OPERATOR_PRECEDENCE: u8 = 50;
+pub(super) const UNARY_ARITHMETIC_OPERATOR_PRECEDENCE: u8 = 50;
+
+/// Binds tighter than `AND` and `OR` but looser than comparisons, so that
+/// `NOT a = 1 AND b` is parsed as `(NOT (a = 1)) AND b`.
+pub(super) const UNARY_NOT_OPERATOR_PRECEDENCE: u8 = 15;
+
+/// Precedence of comparison operators like `=` or `<` and the keyword
+/// operators `LIKE`, `IN`, `BETWEEN` and `IS`.
+pub(super) const COMPARISON_PRECEDENCE: u8 = 20;
+
+/// Precedence used to parse the bounds of `BETWEEN` expressions. It's the same
+/// as comparison operators, so the `AND` that separates the bounds is never
+/// consumed as a logical operator.
+pub(super) const BETWEEN_BOUNDS_PRECEDENCE: u8 = COMPARISON_PRECEDENCE;
+
+/// Default value for [`Parser::max_varchar_length`]. Same as MySQL.
+const DEFAULT_MAX_VARCHAR_LENGTH: usize = 65535;
+
+/// Default maximum nesting level, see [`Parser::max_depth`]. Threads get 2 MB
+/// of stack by default, which in release builds fits more than 400 levels of
+/// the most expensive nesting (subqueries inside subqueries), so 256 leaves
+/// plenty of margin.
+#[cfg(not(debug_assertions))]
+const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// Debug builds need a lot more stack per level, 2 MB are exhausted by about
+/// 60 nested `EXPLAIN` statements.
+#[cfg(debug_assertions)]
+const DEFAULT_MAX_DEPTH: usize = 50;
 
 /// Parser error kind.
 #[derive(Debug, PartialEq)]
@@ -30,6 +60,10 @@ pub(crate) enum ErrorKind {
 
     IntegerOutOfRange(String),
 
+    UnknownStatement { found: String, suggestion: Keyword },
+
+    RecursionLimitExceeded(usize),
+
     Other(String),
 }
 
@@ -91,6 +125,14 @@ impl Display for ErrorKind {
                 "number too big, none of the supported SQL data types can store this: {integer}"
             ),
 
+            ErrorKind::UnknownStatement { found, suggestion } => {
+                write!(f, "unknown statement '{found}', did you mean {suggestion}?")
+            }
+
+            ErrorKind::RecursionLimitExceeded(max) => {
+                write!(f, "input is too deeply nested, maximum depth is {max}")
+            }
+
             ErrorKind::Other(message) => f.write_str(message),
         }
     }
@@ -102,9 +144,63 @@ impl Display for ErrorKind {
 pub(crate) struct ParserError {
     pub kind: ErrorKind,
     pub location: Location,
+    /// End of the span that starts at [`Self::location`], exclusive.
+    pub end: Location,
     pub input: String,
 }
 
+impl ParserError {
+    /// Renders the error like `rustc` diagnostics, showing the offending line
+    /// of `source` with the error span underlined:
+    ///
+    /// ```text
+    /// error: expected 'FROM', found 'customers' instead
+    ///  --> line 1, column 8
+    ///   |
+    /// 1 | DELETE customers WHERE id = 1;
+    ///   |        ^^^^^^^^^
+    /// ```
+    ///
+    /// `source` should be the same input that was given to the [`Parser`],
+    /// which is what the [`Display`] implementation uses.
+    pub fn render(&self, source: &str) -> String {
+        let (line_number, line, caret) = self.snippet(source);
+        let gutter = " ".repeat(line_number.to_string().len());
+
+        format!(
+            "error: {}\n{gutter}--> line {}, column {}\n{gutter} |\n{line_number} | {line}\n{gutter} | {caret}",
+            self.kind, self.location.line, self.location.col,
+        )
+    }
+
+    /// Returns the number and contents of the line in `source` where the
+    /// error is located along with a caret line that underlines the error
+    /// span.
+    fn snippet<'s>(&self, source: &'s str) -> (usize, &'s str, String) {
+        let (line_number, line, white_spaces) = match source.lines().nth(self.location.line - 1) {
+            Some(line) => (self.location.line, line, self.location.col - 1),
+
+            // Unexpected EOF
+            None => {
+                let line_number = source.lines().count().max(1);
+                let line = source.lines().last().unwrap_or_default();
+                (line_number, line, line.chars().count())
+            }
+        };
+
+        // Underline the whole token if it doesn't span multiple lines.
+        let underline = if self.end.line == self.location.line {
+            self.end.col.saturating_sub(self.location.col).max(1)
+        } else {
+            1
+        };
+
+        let caret = format!("{}{}", " ".repeat(white_spaces), "^".repeat(underline));
+
+        (line_number, line, caret)
+    }
+}
+
 impl Display for ParserError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         writeln!(
@@ -113,17 +209,20 @@ impl Display for ParserError {
             self.location.line, self.location.col, self.kind,
         )?;
 
-        let white_spaces = if let Some(line) = self.input.lines().nth(self.location.line - 1) {
-            f.write_str(line)?;
-            self.location.col - 1
-        } else {
-            // Unexpected EOF
-            let line = self.input.lines().last().unwrap();
-            f.write_str(line)?;
-            line.chars().count()
-        };
+        let (_, line, caret) = self.snippet(&self.input);
+
+        write!(f, "{line}\n{caret}")
+    }
+}
 
-        write!(f, "\n{}^", String::from(" ").repeat(white_spaces))
+impl Error for ParserError {
+    /// Errors that come from the [`Tokenizer`] expose the original tokenizer
+    /// error as the source.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ErrorKind::TokenizerError(kind) => Some(kind),
+            _ => None,
+        }
     }
 }
 
@@ -139,6 +238,7 @@ impl From<TokenizerError> for ParserError {
             input,
             kind: ErrorKind::TokenizerError(kind),
             location,
+            end: location,
         }
     }
 }
@@ -161,6 +261,21 @@ pub(crate) struct Parser<'i> {
     tokenizer: Peekable<tokenizer::IntoIter<'i>>,
     /// Location of the last token we've consumed from the iterator.
     location: Location,
+    /// Location right after the last token we've consumed.
+    end: Location,
+    /// Number of `?` placeholders found in the current statement.
+    parameters: usize,
+    /// Distinct named placeholders found in the current statement.
+    named_parameters: Vec<String>,
+    /// Whether the last statement can end at [`Token::Eof`] without
+    /// [`Token::SemiColon`].
+    allow_missing_final_semicolon: bool,
+    /// Maximum length accepted in `VARCHAR(length)`.
+    max_varchar_length: usize,
+    /// Current nesting level, see [`Self::recurse`].
+    depth: usize,
+    /// Maximum value that [`Self::depth`] can reach before we give up.
+    max_depth: usize,
 }
 
 impl<'i> Parser<'i> {
@@ -170,15 +285,64 @@ impl<'i> Parser<'i> {
             input,
             tokenizer: Tokenizer::new(input).into_iter().peekable(),
             location: Location::default(),
+            end: Location::default(),
+            parameters: 0,
+            named_parameters: Vec::new(),
+            allow_missing_final_semicolon: false,
+            max_varchar_length: DEFAULT_MAX_VARCHAR_LENGTH,
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
         }
     }
 
+    /// Sets the maximum nesting level of expressions like `((((1))))` or
+    /// `- - - 1`, subqueries, derived tables and `EXPLAIN` statements. Parsing
+    /// is recursive, so this prevents deeply nested input from overflowing the
+    /// stack. Defaults to [`DEFAULT_MAX_DEPTH`].
+    ///
+    /// Only tests need a lower limit, the server threads are sized for the
+    /// default one.
+    #[cfg(test)]
+    pub fn max_depth(mut self, max: usize) -> Self {
+        self.max_depth = max;
+        self
+    }
+
+    /// Sets the maximum length accepted in `VARCHAR(length)` data types.
+    /// Defaults to [`DEFAULT_MAX_VARCHAR_LENGTH`].
+    ///
+    /// The database always uses the default, this is only compiled for tests.
+    #[cfg(test)]
+    pub fn max_varchar_length(mut self, max: usize) -> Self {
+        self.max_varchar_length = max;
+        self
+    }
+
+    /// Allows the last statement in the input to end without a semicolon,
+    /// which is handy for interactive use. Disabled by default.
+    pub fn allow_missing_final_semicolon(mut self, allow: bool) -> Self {
+        self.allow_missing_final_semicolon = allow;
+        self
+    }
+
+    /// Number of parameters found in the last parsed statement. Named
+    /// parameters that appear multiple times are only counted once.
+    ///
+    /// The analyzer still rejects parameters, so nothing outside of tests can
+    /// bind them yet.
+    #[cfg(test)]
+    pub fn parameter_count(&self) -> usize {
+        self.parameters + self.named_parameters.len()
+    }
+
     /// Attempts to parse the `input` string into a list of [`Statement`]
-    /// instances.
+    /// instances. Empty statements like `;;` are skipped.
     pub fn try_parse(&mut self) -> ParseResult<Vec<Statement>> {
         let mut statements = Vec::new();
 
         loop {
+            self.skip_empty_statements();
+
             match self.peek_token() {
                 Some(Ok(Token::Eof)) | None => return Ok(statements),
                 _ => statements.push(self.parse_statement()?),
@@ -186,26 +350,85 @@ impl<'i> Parser<'i> {
         }
     }
 
-    /// Parses a single SQL statement in the input string.
+    /// Consumes the parser and returns an iterator that parses one
+    /// [`Statement`] at a time.
     ///
-    /// If the statement terminator is not found then it returns [`Err`].
-    pub fn parse_statement(&mut self) -> ParseResult<Statement> {
-        let statement = match self.expect_one_of(&Self::supported_statements())? {
-            Keyword::Select => {
-                let columns = self.parse_comma_separated_expressions()?;
-                self.expect_keyword(Keyword::From)?;
+    /// Unlike [`Self::try_parse`] nothing is buffered, so callers can process
+    /// large scripts lazily and stop whenever they want. The iterator ends at
+    /// [`Token::Eof`] or right after yielding the first error.
+    pub fn statements(self) -> Statements<'i> {
+        Statements {
+            parser: self,
+            done: false,
+        }
+    }
 
-                let (from, r#where) = self.parse_from_and_optional_where()?;
+    /// Same as [`Self::try_parse`] but doesn't stop at the first error.
+    ///
+    /// When a statement can't be parsed the error is recorded and the parser
+    /// skips everything up to the next [`Token::SemiColon`], then continues
+    /// with the next statement. Errors returned by the tokenizer can't be
+    /// recovered from because the tokenizer doesn't advance past the bad
+    /// input, so parsing stops there.
+    ///
+    /// The database executes one statement at a time, so this is only
+    /// compiled for tests until there's a way to run whole scripts.
+    #[cfg(test)]
+    pub fn try_parse_all(&mut self) -> Result<Vec<Statement>, Vec<ParserError>> {
+        let mut statements = Vec::new();
+        let mut errors = Vec::new();
 
-                let order_by = self.parse_optional_order_by()?;
+        loop {
+            self.skip_empty_statements();
 
-                Statement::Select {
-                    columns,
-                    from,
-                    r#where,
-                    order_by,
-                }
+            match self.peek_token() {
+                Some(Ok(Token::Eof)) | None => break,
+                _ => match self.parse_statement() {
+                    Ok(statement) => statements.push(statement),
+                    Err(error) => {
+                        let recover = match &error.kind {
+                            ErrorKind::TokenizerError(_) | ErrorKind::UnexpectedEof => {
+                                errors.push(error);
+                                break;
+                            }
+                            // The terminator is already consumed.
+                            ErrorKind::Expected { found, .. }
+                            | ErrorKind::ExpectedOneOf { found, .. }
+                            | ErrorKind::UnexpectedOrUnsupported(found) => {
+                                *found != Token::SemiColon
+                            }
+                            _ => true,
+                        };
+
+                        errors.push(error);
+
+                        if recover {
+                            if let Err(error) = self.recover_to_semicolon() {
+                                errors.push(error);
+                                break;
+                            }
+                        }
+                    }
+                },
             }
+        }
+
+        if errors.is_empty() {
+            Ok(statements)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Parses a single SQL statement in the input string.
+    ///
+    /// If the statement terminator is not found then it returns [`Err`].
+    pub fn parse_statement(&mut self) -> ParseResult<Statement> {
+        self.parameters = 0;
+        self.named_parameters.clear();
+
+        let statement = match self.expect_statement_keyword()? {
+            keyword @ (Keyword::Select | Keyword::With) => self.parse_query(keyword)?,
 
             Keyword::Create => {
                 let keyword = self.expect_one_of(&[
@@ -213,15 +436,32 @@ impl<'i> Parser<'i> {
                     Keyword::Table,
                     Keyword::Unique,
                     Keyword::Index,
+                    Keyword::View,
                 ])?;
 
                 Statement::Create(match keyword {
-                    Keyword::Database => Create::Database(self.parse_identifier()?),
+                    Keyword::Database => {
+                        let if_not_exists = self.parse_if_not_exists()?;
+                        let name = self.parse_identifier()?;
 
-                    Keyword::Table => Create::Table {
-                        name: self.parse_identifier()?,
-                        columns: self.parse_column_definitions()?,
-                    },
+                        Create::Database {
+                            name,
+                            if_not_exists,
+                        }
+                    }
+
+                    Keyword::Table => {
+                        let if_not_exists = self.parse_if_not_exists()?;
+                        let name = self.parse_identifier()?;
+                        let (columns, table_constraints) = self.parse_column_definitions()?;
+
+                        Create::Table {
+                            name,
+                            columns,
+                            table_constraints,
+                            if_not_exists,
+                        }
+                    }
 
                     Keyword::Unique | Keyword::Index => {
                         let unique = keyword == Keyword::Unique;
@@ -234,18 +474,26 @@ impl<'i> Parser<'i> {
                         self.expect_keyword(Keyword::On)?;
                         let table = self.parse_identifier()?;
 
-                        self.expect_token(Token::LeftParen)?;
-                        let column = self.parse_identifier()?;
-                        self.expect_token(Token::RightParen)?;
+                        let columns = self.parse_identifier_list()?;
 
                         Create::Index {
                             name,
                             table,
-                            column,
+                            columns,
                             unique,
                         }
                     }
 
+                    Keyword::View => {
+                        let name = self.parse_identifier()?;
+                        self.expect_keyword(Keyword::As)?;
+                        // Views can only store queries.
+                        let keyword = self.expect_one_of(&[Keyword::Select, Keyword::With])?;
+                        let query = Box::new(self.parse_query(keyword)?);
+
+                        Create::View { name, query }
+                    }
+
                     _ => unreachable!(),
                 })
             }
@@ -256,62 +504,166 @@ impl<'i> Parser<'i> {
 
                 let columns = self.parse_comma_separated(Self::parse_assignment, false)?;
                 let r#where = self.parse_optional_where()?;
+                let returning = self.parse_optional_returning()?;
 
                 Statement::Update {
                     table,
                     columns,
                     r#where,
+                    returning,
                 }
             }
 
             Keyword::Insert => {
                 self.expect_keyword(Keyword::Into)?;
                 let into = self.parse_identifier()?;
+                // Empty column list means all columns in definition order.
                 let columns = self.parse_optional_identifier_list()?;
 
-                self.expect_keyword(Keyword::Values)?;
-                let values = self.parse_comma_separated(Self::parse_expression, true)?;
+                let source =
+                    match self.expect_one_of(&[Keyword::Values, Keyword::Select, Keyword::With])? {
+                        Keyword::Values => InsertSource::Values(self.parse_comma_separated(
+                            |parser| parser.parse_comma_separated(Self::parse_expression, true),
+                            false,
+                        )?),
+
+                        keyword => InsertSource::Select(Box::new(self.parse_query(keyword)?)),
+                    };
+
+                let returning = self.parse_optional_returning()?;
 
                 Statement::Insert {
                     into,
                     columns,
-                    values,
+                    source,
+                    returning,
                 }
             }
 
             Keyword::Delete => {
                 self.expect_keyword(Keyword::From)?;
                 let (from, r#where) = self.parse_from_and_optional_where()?;
+                let returning = self.parse_optional_returning()?;
 
-                Statement::Delete { from, r#where }
+                Statement::Delete {
+                    from,
+                    r#where,
+                    returning,
+                }
             }
 
             Keyword::Drop => {
-                let keyword = self.expect_one_of(&[Keyword::Database, Keyword::Table])?;
-                let identifier = self.parse_identifier()?;
+                let keyword = self.expect_one_of(&[
+                    Keyword::Database,
+                    Keyword::Table,
+                    Keyword::Index,
+                    Keyword::View,
+                ])?;
+
+                let if_exists = match keyword {
+                    Keyword::Database | Keyword::Table => self.parse_if_exists()?,
+                    _ => false,
+                };
+
+                let name = self.parse_identifier()?;
 
                 Statement::Drop(match keyword {
-                    Keyword::Database => Drop::Database(identifier),
-                    Keyword::Table => Drop::Table(identifier),
+                    Keyword::Database => Drop::Database { name, if_exists },
+                    Keyword::Table => Drop::Table { name, if_exists },
+                    Keyword::Index => Drop::Index(name),
+                    Keyword::View => Drop::View(name),
                     _ => unreachable!(),
                 })
             }
 
+            Keyword::Truncate => {
+                self.consume_optional_keyword(Keyword::Table);
+                Statement::Truncate(self.parse_identifier()?)
+            }
+
+            Keyword::Alter => {
+                self.expect_keyword(Keyword::Table)?;
+                let table = self.parse_identifier()?;
+
+                let action =
+                    match self.expect_one_of(&[Keyword::Add, Keyword::Drop, Keyword::Rename])? {
+                        Keyword::Add => {
+                            self.consume_optional_keyword(Keyword::Column);
+                            AlterAction::AddColumn(self.parse_column()?)
+                        }
+
+                        Keyword::Drop => {
+                            self.consume_optional_keyword(Keyword::Column);
+                            AlterAction::DropColumn(self.parse_identifier()?)
+                        }
+
+                        Keyword::Rename => {
+                            if self.consume_optional_keyword(Keyword::Column) {
+                                let from = self.parse_identifier()?;
+                                self.expect_keyword(Keyword::To)?;
+                                let to = self.parse_identifier()?;
+                                AlterAction::RenameColumn { from, to }
+                            } else {
+                                self.expect_keyword(Keyword::To)?;
+                                AlterAction::RenameTable(self.parse_identifier()?)
+                            }
+                        }
+
+                        _ => unreachable!(),
+                    };
+
+                Statement::AlterTable { table, action }
+            }
+
             Keyword::Start => {
                 self.expect_keyword(Keyword::Transaction)?;
                 Statement::StartTransaction
             }
 
+            Keyword::Begin => Statement::StartTransaction,
+
             Keyword::Commit => Statement::Commit,
 
-            Keyword::Rollback => Statement::Rollback,
+            Keyword::Rollback => {
+                if self.consume_optional_keyword(Keyword::To) {
+                    self.consume_optional_keyword(Keyword::Savepoint);
+                    Statement::RollbackTo(self.parse_identifier()?)
+                } else {
+                    Statement::Rollback
+                }
+            }
+
+            Keyword::Savepoint => Statement::Savepoint(self.parse_identifier()?),
+
+            Keyword::Explain => {
+                return Ok(Statement::Explain(Box::new(
+                    self.recurse(Self::parse_statement)?,
+                )))
+            }
+
+            Keyword::Show => match self.expect_one_of(&[Keyword::Tables, Keyword::Columns])? {
+                Keyword::Tables => Statement::ShowTables,
+
+                Keyword::Columns => {
+                    self.expect_keyword(Keyword::From)?;
+                    Statement::ShowColumns {
+                        table: self.parse_identifier()?,
+                    }
+                }
+
+                _ => unreachable!(),
+            },
+
+            // DESC is only a shorthand for DESCRIBE at the start of a
+            // statement. Anywhere else it means descending order.
+            Keyword::Describe | Keyword::Desc => Statement::Describe(self.parse_identifier()?),
 
-            Keyword::Explain => return Ok(Statement::Explain(Box::new(self.parse_statement()?))),
+            Keyword::Use => Statement::Use(self.parse_identifier()?),
 
             _ => unreachable!(),
         };
 
-        self.expect_token(Token::SemiColon)?;
+        self.expect_semicolon()?;
         Ok(statement)
     }
 
@@ -332,8 +684,45 @@ impl<'i> Parser<'i> {
         self.parse_expr(0)
     }
 
+    /// Parses the whole `input` as one single expression like `x + 1 > 2`.
+    ///
+    /// Unlike [`Self::parse_expression`] this one fails if there's anything
+    /// left after the expression, so it's suitable for embedding the expression
+    /// grammar in other places where statements make no sense.
+    pub fn parse_single_expression(input: &str) -> ParseResult<Expression> {
+        let mut parser = Parser::new(input);
+        let expr = parser.parse_expression()?;
+        parser.expect_token(Token::Eof)?;
+
+        Ok(expr)
+    }
+
+    /// Calls `parse` one nesting level deeper. Parsing is recursive, so
+    /// everything that can nest without limits (expressions, subqueries,
+    /// derived tables and `EXPLAIN`) goes through here to make sure we return
+    /// an error before overflowing the stack.
+    fn recurse<T>(&mut self, parse: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        if self.depth >= self.max_depth {
+            return Err(self.error(ErrorKind::RecursionLimitExceeded(self.max_depth)));
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+
+        result
+    }
+
     /// Main TDOP loop.
+    ///
+    /// Every recursive step of expression parsing goes through here, so this
+    /// is one of the places where we keep track of [`Self::depth`].
     fn parse_expr(&mut self, precedence: u8) -> ParseResult<Expression> {
+        self.recurse(|parser| parser.parse_expr_with_precedence(precedence))
+    }
+
+    /// See [`Self::parse_expr`].
+    fn parse_expr_with_precedence(&mut self, precedence: u8) -> ParseResult<Expression> {
         let mut expr = self.parse_prefix()?;
         let mut next_precedence = self.get_next_precedence();
 
@@ -347,13 +736,53 @@ impl<'i> Parser<'i> {
 
     /// Parses the beginning of an expression.
     fn parse_prefix(&mut self) -> ParseResult<Expression> {
-        match self.next_token()? {
-            Token::Identifier(ident) => Ok(Expression::Identifier(ident)),
+        let token = match self.next_token()? {
+            Token::Keyword(keyword) if Self::non_reserved_keywords().contains(&keyword) => {
+                Token::Identifier(self.keyword_as_identifier(Token::Keyword(keyword))?)
+            }
+            token => token,
+        };
+
+        match token {
+            Token::Identifier(ident) => {
+                if self.consume_optional_token(Token::LeftParen) {
+                    self.parse_function_call(ident)
+                } else if self.consume_optional_token(Token::Dot) {
+                    self.parse_qualified_identifier(ident)
+                } else {
+                    Ok(Expression::Identifier(ident))
+                }
+            }
+
             Token::Mul => Ok(Expression::Wildcard),
 
+            Token::Placeholder => {
+                self.parameters += 1;
+                Ok(Expression::Parameter(self.parameters))
+            }
+
+            Token::NamedPlaceholder(name) => {
+                if !self.named_parameters.contains(&name) {
+                    self.named_parameters.push(name.clone());
+                }
+                Ok(Expression::NamedParameter(name))
+            }
+
             Token::String(string) => Ok(Expression::Value(Value::String(string))),
             Token::Keyword(Keyword::True) => Ok(Expression::Value(Value::Bool(true))),
             Token::Keyword(Keyword::False) => Ok(Expression::Value(Value::Bool(false))),
+            Token::Keyword(Keyword::Null) => Ok(Expression::Value(Value::Null)),
+            Token::Number(num) if num.starts_with("0x") || num.starts_with("0X") => {
+                Ok(Expression::Value(Value::Number(
+                    i128::from_str_radix(&num[2..], 16)
+                        .map_err(|_| self.error(ErrorKind::IntegerOutOfRange(num)))?,
+                )))
+            }
+            Token::Number(num) if num.contains(['.', 'e', 'E']) => {
+                Ok(Expression::Value(Value::Float(num.parse().map_err(
+                    |_| self.error(ErrorKind::Other(format!("invalid float {num}"))),
+                )?)))
+            }
             Token::Number(num) => Ok(Expression::Value(Value::Number(
                 num.parse()
                     .map_err(|_| self.error(ErrorKind::IntegerOutOfRange(num)))?,
@@ -371,10 +800,54 @@ impl<'i> Parser<'i> {
                 Ok(Expression::UnaryOperation { operator, expr })
             }
 
+            Token::Keyword(Keyword::Exists) => self.parse_exists(false),
+
+            Token::Keyword(Keyword::Case) => self.parse_case(),
+
+            Token::Keyword(Keyword::Cast) => {
+                self.expect_token(Token::LeftParen)?;
+                let expr = Box::new(self.parse_expression()?);
+                self.expect_keyword(Keyword::As)?;
+                let data_type = self.parse_data_type()?;
+                self.expect_token(Token::RightParen)?;
+
+                Ok(Expression::Cast { expr, data_type })
+            }
+
+            Token::Keyword(Keyword::Not) => {
+                if self.consume_optional_keyword(Keyword::Exists) {
+                    return self.parse_exists(true);
+                }
+
+                let expr = Box::new(self.parse_expr(UNARY_NOT_OPERATOR_PRECEDENCE)?);
+
+                Ok(Expression::UnaryOperation {
+                    operator: UnaryOperator::Not,
+                    expr,
+                })
+            }
+
             Token::LeftParen => {
-                let expr = self.parse_expression()?;
+                let expr = match self.consume_one_of(&[Keyword::Select, Keyword::With]) {
+                    Keyword::None => {
+                        let expr = self.parse_expression()?;
+
+                        // (a, b) is a row value but (a) is just a nested expression.
+                        if self.consume_optional_token(Token::Comma) {
+                            let mut items = vec![expr];
+                            items.extend(self.parse_comma_separated_expressions()?);
+                            Expression::Row(items)
+                        } else {
+                            Expression::Nested(Box::new(expr))
+                        }
+                    }
+
+                    keyword => Expression::Subquery(Box::new(self.parse_query(keyword)?)),
+                };
+
                 self.expect_token(Token::RightParen)?;
-                Ok(Expression::Nested(Box::new(expr)))
+
+                Ok(expr)
             }
 
             unexpected => Err(self.error(ErrorKind::ExpectedOneOf {
@@ -395,26 +868,61 @@ impl<'i> Parser<'i> {
     /// Parses an infix expression in the form of
     /// (left expr | operator | right expr).
     fn parse_infix(&mut self, left: Expression, precedence: u8) -> ParseResult<Expression> {
-        let operator = match self.next_token()? {
-            Token::Plus => BinaryOperator::Plus,
-            Token::Minus => BinaryOperator::Minus,
-            Token::Div => BinaryOperator::Div,
-            Token::Mul => BinaryOperator::Mul,
-            Token::Eq => BinaryOperator::Eq,
-            Token::Neq => BinaryOperator::Neq,
-            Token::Gt => BinaryOperator::Gt,
-            Token::GtEq => BinaryOperator::GtEq,
-            Token::Lt => BinaryOperator::Lt,
-            Token::LtEq => BinaryOperator::LtEq,
-            Token::Keyword(Keyword::And) => BinaryOperator::And,
-            Token::Keyword(Keyword::Or) => BinaryOperator::Or,
+        let token = self.next_token()?;
 
-            unexpected => Err(self.error(ErrorKind::ExpectedOneOf {
-                expected: Self::supported_operators(),
-                found: unexpected,
-            }))?,
+        let operator = match token {
+            Token::Keyword(Keyword::Like) => return self.parse_like(left, precedence, false),
+            Token::Keyword(Keyword::ILike) => return self.parse_like(left, precedence, true),
+
+            Token::Keyword(Keyword::In) => return self.parse_in_list(left, false),
+
+            Token::Keyword(Keyword::Between) => return self.parse_between(left, false),
+
+            Token::Keyword(Keyword::Is) => {
+                let negated = self.consume_optional_keyword(Keyword::Not);
+                self.expect_keyword(Keyword::Null)?;
+
+                return Ok(Expression::IsNull {
+                    expr: Box::new(left),
+                    negated,
+                });
+            }
+
+            Token::Keyword(Keyword::Not) => {
+                return match self.expect_one_of(&[Keyword::In, Keyword::Between])? {
+                    Keyword::In => self.parse_in_list(left, true),
+                    Keyword::Between => self.parse_between(left, true),
+                    _ => unreachable!(),
+                };
+            }
+
+            _ => match Self::binary_operator(&token) {
+                Some(operator) => operator,
+                None => Err(self.error(ErrorKind::ExpectedOneOf {
+                    expected: Self::supported_operators(),
+                    found: token,
+                }))?,
+            },
         };
 
+        let is_comparison = matches!(
+            operator,
+            BinaryOperator::Eq
+                | BinaryOperator::Neq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq
+        );
+
+        if is_comparison {
+            match self.consume_one_of(&[Keyword::Any, Keyword::All]) {
+                Keyword::Any => return self.parse_quantified(left, operator, Quantifier::Any),
+                Keyword::All => return self.parse_quantified(left, operator, Quantifier::All),
+                _ => {}
+            }
+        }
+
         Ok(Expression::BinaryOperation {
             left: Box::new(left),
             operator,
@@ -422,66 +930,331 @@ impl<'i> Parser<'i> {
         })
     }
 
-    /// Returns the precedence value of the next operator in the stream.
-    fn get_next_precedence(&mut self) -> u8 {
-        let Some(Ok(token)) = self.peek_token() else {
-            return 0;
-        };
-
-        match token {
-            Token::Keyword(Keyword::Or) => 5,
-            Token::Keyword(Keyword::And) => 10,
-            Token::Eq | Token::Neq | Token::Gt | Token::GtEq | Token::Lt | Token::LtEq => 20,
-            Token::Plus | Token::Minus => 30,
-            Token::Mul | Token::Div => 40,
-            _ => 0,
+    /// Parses the arguments of function calls like `COUNT(*)` or `SUM(price)`.
+    /// The argument list can start with `DISTINCT` as in `COUNT(DISTINCT id)`,
+    /// the [`super::analyzer`] decides which functions accept it.
+    ///
+    /// The opening parenthesis must be consumed before calling this function.
+    fn parse_function_call(&mut self, name: String) -> ParseResult<Expression> {
+        let distinct = self.consume_optional_keyword(Keyword::Distinct);
+
+        if !distinct && self.consume_optional_token(Token::RightParen) {
+            return Ok(Expression::Function {
+                name,
+                distinct,
+                args: vec![],
+            });
         }
-    }
-
-    /// Parses a column definition for `CREATE TABLE` statements.
-    fn parse_column(&mut self) -> ParseResult<Column> {
-        let name = self.parse_identifier()?;
 
-        let data_type = match self.expect_one_of(&Self::supported_data_types())? {
-            int @ (Keyword::Int | Keyword::BigInt) => {
-                let unsigned = self.consume_optional_keyword(Keyword::Unsigned);
-                match (int, unsigned) {
-                    (Keyword::Int, true) => DataType::UnsignedInt,
-                    (Keyword::Int, false) => DataType::Int,
-                    (Keyword::BigInt, true) => DataType::UnsignedBigInt,
-                    (Keyword::BigInt, false) => DataType::BigInt,
-                    _ => unreachable!(),
-                }
-            }
+        let args = self.parse_comma_separated_expressions()?;
+        self.expect_token(Token::RightParen)?;
 
-            Keyword::Varchar => {
-                self.expect_token(Token::LeftParen)?;
+        if args.len() > 1 && args.contains(&Expression::Wildcard) {
+            return Err(self.error(ErrorKind::Other(format!(
+                "wildcard (*) must be the only argument of function {name}()"
+            ))));
+        }
 
-                let length = match self.next_token()? {
-                    Token::Number(num) => num.parse().map_err(|_| {
-                        self.error(ErrorKind::Other(
-                            "incorrect VARCHAR length definition".into(),
-                        ))
-                    })?,
-                    unexpected => Err(self.error(ErrorKind::Expected {
-                        expected: Token::Number(Default::default()),
-                        found: unexpected,
-                    }))?,
-                };
+        if distinct && args.contains(&Expression::Wildcard) {
+            return Err(self.error(ErrorKind::Other(format!(
+                "DISTINCT can't be used with wildcard (*) in function {name}()"
+            ))));
+        }
 
-                self.expect_token(Token::RightParen)?;
-                DataType::Varchar(length)
-            }
+        Ok(Expression::Function {
+            name,
+            distinct,
+            args,
+        })
+    }
 
-            Keyword::Bool => DataType::Bool,
+    /// Parses the pattern of `LIKE` expressions and the optional `ESCAPE`
+    /// character that follows it.
+    ///
+    /// The `LIKE` or `ILIKE` keyword must be consumed before calling this
+    /// function.
+    fn parse_like(
+        &mut self,
+        expr: Expression,
+        precedence: u8,
+        case_insensitive: bool,
+    ) -> ParseResult<Expression> {
+        let pattern = self.parse_expr(precedence)?;
+
+        let escape = if self.consume_optional_keyword(Keyword::Escape) {
+            let start = self.peek_location();
+            let string = match self.next_token()? {
+                Token::String(string) => string,
+                unexpected => Err(self.error(ErrorKind::Expected {
+                    expected: Token::String(Default::default()),
+                    found: unexpected,
+                }))?,
+            };
+
+            let mut chars = string.chars();
+            let (Some(escape), None) = (chars.next(), chars.next()) else {
+                return Err(self.error_from(
+                    start,
+                    ErrorKind::Other(format!(
+                        "ESCAPE must be a single character, found '{string}'"
+                    )),
+                ));
+            };
+
+            Some(escape)
+        } else {
+            None
+        };
 
-            _ => unreachable!(),
+        Ok(Expression::Like {
+            expr: Box::new(expr),
+            pattern: Box::new(pattern),
+            escape,
+            case_insensitive,
+        })
+    }
+
+    /// Parses the `(SELECT ...)` subquery of `price > ALL (SELECT ...)`.
+    ///
+    /// The comparison operator and the quantifier must be consumed before
+    /// calling this function.
+    fn parse_quantified(
+        &mut self,
+        left: Expression,
+        operator: BinaryOperator,
+        quantifier: Quantifier,
+    ) -> ParseResult<Expression> {
+        self.expect_token(Token::LeftParen)?;
+        let keyword = self.expect_one_of(&[Keyword::Select, Keyword::With])?;
+        let subquery = Box::new(self.parse_query(keyword)?);
+        self.expect_token(Token::RightParen)?;
+
+        Ok(Expression::Quantified {
+            left: Box::new(left),
+            operator,
+            quantifier,
+            subquery,
+        })
+    }
+
+    /// Parses the parenthesized list of `[NOT] IN (1, 2, 3)` expressions or
+    /// the `[NOT] IN (SELECT ...)` subquery.
+    ///
+    /// The `IN` keyword must be consumed before calling this function.
+    fn parse_in_list(&mut self, expr: Expression, negated: bool) -> ParseResult<Expression> {
+        self.expect_token(Token::LeftParen)?;
+
+        let expr = match self.consume_one_of(&[Keyword::Select, Keyword::With]) {
+            Keyword::None => Expression::InList {
+                expr: Box::new(expr),
+                list: self.parse_comma_separated(Self::parse_expression, false)?,
+                negated,
+            },
+
+            keyword => Expression::InSubquery {
+                expr: Box::new(expr),
+                subquery: Box::new(self.parse_query(keyword)?),
+                negated,
+            },
+        };
+
+        self.expect_token(Token::RightParen)?;
+
+        Ok(expr)
+    }
+
+    /// Parses the `([SELECT ...])` that follows `[NOT] EXISTS`.
+    ///
+    /// The `EXISTS` keyword must be consumed before calling this function.
+    fn parse_exists(&mut self, negated: bool) -> ParseResult<Expression> {
+        self.expect_token(Token::LeftParen)?;
+        let keyword = self.expect_one_of(&[Keyword::Select, Keyword::With])?;
+        let subquery = Box::new(self.parse_query(keyword)?);
+        self.expect_token(Token::RightParen)?;
+
+        Ok(Expression::Exists { subquery, negated })
+    }
+
+    /// Parses the rest of a `CASE` expression up to and including `END`.
+    ///
+    /// The `CASE` keyword must be consumed before calling this function.
+    fn parse_case(&mut self) -> ParseResult<Expression> {
+        let operand = match self.peek_token() {
+            Some(Ok(Token::Keyword(Keyword::When))) => None,
+            _ => Some(Box::new(self.parse_expression()?)),
+        };
+
+        self.expect_keyword(Keyword::When)?;
+
+        let mut when_then = Vec::new();
+        loop {
+            let condition = self.parse_expression()?;
+            self.expect_keyword(Keyword::Then)?;
+            when_then.push((condition, self.parse_expression()?));
+
+            if !self.consume_optional_keyword(Keyword::When) {
+                break;
+            }
+        }
+
+        let else_result = if self.consume_optional_keyword(Keyword::Else) {
+            Some(Box::new(self.parse_expression()?))
+        } else {
+            None
+        };
+
+        self.expect_keyword(Keyword::End)?;
+
+        Ok(Expression::Case {
+            operand,
+            when_then,
+            else_result,
+        })
+    }
+
+    /// Parses the `low AND high` bounds of `[NOT] BETWEEN` expressions.
+    ///
+    /// The `BETWEEN` keyword must be consumed before calling this function.
+    fn parse_between(&mut self, expr: Expression, negated: bool) -> ParseResult<Expression> {
+        let low = self.parse_expr(BETWEEN_BOUNDS_PRECEDENCE)?;
+        self.expect_keyword(Keyword::And)?;
+        let high = self.parse_expr(BETWEEN_BOUNDS_PRECEDENCE)?;
+
+        Ok(Expression::Between {
+            expr: Box::new(expr),
+            low: Box::new(low),
+            high: Box::new(high),
+            negated,
+        })
+    }
+
+    /// Returns the precedence value of the next operator in the stream.
+    ///
+    /// Binary operators take theirs from [`BinaryOperator::precedence`], the
+    /// keyword operators that build other expressions bind like comparisons.
+    fn get_next_precedence(&mut self) -> u8 {
+        let Some(Ok(token)) = self.peek_token() else {
+            return 0;
         };
 
+        match token {
+            Token::Keyword(
+                Keyword::Like
+                | Keyword::ILike
+                | Keyword::In
+                | Keyword::Not
+                | Keyword::Between
+                | Keyword::Is,
+            ) => COMPARISON_PRECEDENCE,
+            token => Self::binary_operator(token).map_or(0, |operator| operator.precedence()),
+        }
+    }
+
+    /// Maps the tokens that represent a [`BinaryOperator`] to that operator.
+    fn binary_operator(token: &Token) -> Option<BinaryOperator> {
+        Some(match token {
+            Token::Plus => BinaryOperator::Plus,
+            Token::Minus => BinaryOperator::Minus,
+            Token::Div => BinaryOperator::Div,
+            Token::Percent => BinaryOperator::Mod,
+            Token::BitAnd => BinaryOperator::BitAnd,
+            Token::BitOr => BinaryOperator::BitOr,
+            Token::BitXor => BinaryOperator::BitXor,
+            Token::ShiftLeft => BinaryOperator::ShiftLeft,
+            Token::ShiftRight => BinaryOperator::ShiftRight,
+            Token::Mul => BinaryOperator::Mul,
+            Token::Eq => BinaryOperator::Eq,
+            Token::Neq => BinaryOperator::Neq,
+            Token::Gt => BinaryOperator::Gt,
+            Token::GtEq => BinaryOperator::GtEq,
+            Token::Lt => BinaryOperator::Lt,
+            Token::LtEq => BinaryOperator::LtEq,
+            Token::Keyword(Keyword::And) => BinaryOperator::And,
+            Token::Keyword(Keyword::Or) => BinaryOperator::Or,
+            _ => return None,
+        })
+    }
+
+    /// Parses a data type such as `INT UNSIGNED` or `VARCHAR(255)`.
+    fn parse_data_type(&mut self) -> ParseResult<DataType> {
+        Ok(match self.expect_one_of(&Self::supported_data_types())? {
+            int @ (Keyword::SmallInt | Keyword::Int | Keyword::BigInt) => {
+                let unsigned = self.consume_optional_keyword(Keyword::Unsigned);
+                match (int, unsigned) {
+                    (Keyword::SmallInt, true) => DataType::UnsignedSmallInt,
+                    (Keyword::SmallInt, false) => DataType::SmallInt,
+                    (Keyword::Int, true) => DataType::UnsignedInt,
+                    (Keyword::Int, false) => DataType::Int,
+                    (Keyword::BigInt, true) => DataType::UnsignedBigInt,
+                    (Keyword::BigInt, false) => DataType::BigInt,
+                    _ => unreachable!(),
+                }
+            }
+
+            Keyword::Varchar => {
+                self.expect_token(Token::LeftParen)?;
+                let length = self.parse_type_argument("incorrect VARCHAR length definition")?;
+
+                if length == 0 || length > self.max_varchar_length {
+                    return Err(self.error(ErrorKind::Other(format!(
+                        "VARCHAR length must be between 1 and {}, found {length}",
+                        self.max_varchar_length
+                    ))));
+                }
+
+                self.expect_token(Token::RightParen)?;
+
+                DataType::Varchar(length)
+            }
+
+            Keyword::Text => DataType::Text,
+
+            Keyword::Date => DataType::Date,
+
+            Keyword::Timestamp => DataType::Timestamp,
+
+            Keyword::Decimal | Keyword::Numeric => {
+                self.expect_token(Token::LeftParen)?;
+                let precision =
+                    self.parse_type_argument("incorrect DECIMAL precision definition")?;
+                self.expect_token(Token::Comma)?;
+                let scale = self.parse_type_argument("incorrect DECIMAL scale definition")?;
+                self.expect_token(Token::RightParen)?;
+
+                if scale > precision {
+                    return Err(self.error(ErrorKind::Other(format!(
+                        "DECIMAL scale {scale} cannot be greater than precision {precision}"
+                    ))));
+                }
+
+                DataType::Decimal { precision, scale }
+            }
+
+            Keyword::Bool => DataType::Bool,
+
+            Keyword::Float | Keyword::Real | Keyword::Double => DataType::Float,
+
+            _ => unreachable!(),
+        })
+    }
+
+    /// Parses a column definition for `CREATE TABLE` statements.
+    fn parse_column(&mut self) -> ParseResult<Column> {
+        let name = self.parse_identifier()?;
+        let data_type = self.parse_data_type()?;
+
         let mut constraints = Vec::new();
 
         while let Some(constraint) = self
-            .consume_one_of(&[Keyword::Primary, Keyword::Unique])
+            .consume_one_of(&[
+                Keyword::Primary,
+                Keyword::Unique,
+                Keyword::Not,
+                Keyword::References,
+                Keyword::Default,
+                Keyword::Check,
+                Keyword::AutoIncrement,
+            ])
             .as_option()
         {
             match constraint {
@@ -492,6 +1265,30 @@ impl<'i> Parser<'i> {
 
                 Keyword::Unique => constraints.push(Constraint::Unique),
 
+                Keyword::Not => {
+                    self.expect_keyword(Keyword::Null)?;
+                    constraints.push(Constraint::NotNull);
+                }
+
+                Keyword::References => constraints.push(self.parse_references()?),
+
+                // Stop before comparisons so that NOT in `DEFAULT 0 NOT NULL`
+                // is not parsed as the infix operator of NOT IN or NOT BETWEEN.
+                Keyword::Default => {
+                    constraints.push(Constraint::Default(self.parse_expr(COMPARISON_PRECEDENCE)?))
+                }
+
+                Keyword::Check => constraints.push(Constraint::Check(self.parse_check()?)),
+
+                Keyword::AutoIncrement => {
+                    if !data_type.is_integer() {
+                        return Err(self.error(ErrorKind::Other(format!(
+                            "AUTO_INCREMENT is not allowed on {data_type} columns"
+                        ))));
+                    }
+                    constraints.push(Constraint::AutoIncrement);
+                }
+
                 _ => unreachable!(),
             }
         }
@@ -503,6 +1300,22 @@ impl<'i> Parser<'i> {
         })
     }
 
+    /// Parses a numeric argument of a data type like the `255` in
+    /// `VARCHAR(255)`.
+    ///
+    /// The given `error` message is returned if the number doesn't fit in `T`.
+    fn parse_type_argument<T: FromStr>(&mut self, error: &str) -> ParseResult<T> {
+        match self.next_token()? {
+            Token::Number(num) => num
+                .parse()
+                .map_err(|_| self.error(ErrorKind::Other(error.into()))),
+            unexpected => Err(self.error(ErrorKind::Expected {
+                expected: Token::Number(Default::default()),
+                found: unexpected,
+            })),
+        }
+    }
+
     /// Parses an assignment like the ones used in `UPDATE` statements.
     fn parse_assignment(&mut self) -> ParseResult<Assignment> {
         let identifier = self.parse_identifier()?;
@@ -517,12 +1330,64 @@ impl<'i> Parser<'i> {
     fn parse_identifier(&mut self) -> ParseResult<String> {
         self.next_token().and_then(|token| match token {
             Token::Identifier(ident) => Ok(ident),
+            _ => self.keyword_as_identifier(token),
+        })
+    }
+
+    /// Fallback for tokens found where an identifier is expected.
+    ///
+    /// [`Self::non_reserved_keywords`] are accepted as identifiers so that
+    /// columns and tables can be named `key`, `date`, etc. Keywords don't
+    /// preserve the original case, so the identifier is always lowercase.
+    fn keyword_as_identifier(&self, token: Token) -> ParseResult<String> {
+        match token {
+            Token::Keyword(keyword) if Self::non_reserved_keywords().contains(&keyword) => {
+                Ok(keyword.to_string().to_lowercase())
+            }
 
             _ => Err(self.error(ErrorKind::Expected {
                 expected: Token::Identifier(Default::default()),
                 found: token,
             })),
-        })
+        }
+    }
+
+    /// Parses the optional `IF NOT EXISTS` clause of `CREATE` statements.
+    fn parse_if_not_exists(&mut self) -> ParseResult<bool> {
+        if !self.consume_optional_keyword(Keyword::If) {
+            return Ok(false);
+        }
+
+        self.expect_keyword(Keyword::Not)?;
+        self.expect_keyword(Keyword::Exists)?;
+
+        Ok(true)
+    }
+
+    /// Parses the optional `IF EXISTS` clause of `DROP` statements.
+    fn parse_if_exists(&mut self) -> ParseResult<bool> {
+        if !self.consume_optional_keyword(Keyword::If) {
+            return Ok(false);
+        }
+
+        self.expect_keyword(Keyword::Exists)?;
+
+        Ok(true)
+    }
+
+    /// Parses whatever comes after `table.`, which can be either a column name
+    /// or a wildcard.
+    fn parse_qualified_identifier(&mut self, table: String) -> ParseResult<Expression> {
+        match self.next_token()? {
+            Token::Identifier(column) => Ok(Expression::QualifiedIdentifier { table, column }),
+
+            Token::Mul => Ok(Expression::QualifiedWildcard(table)),
+
+            token => Ok(Expression::QualifiedIdentifier {
+                table,
+                column: self.keyword_as_identifier(token)?,
+            }),
+        }
     }
 
     /// Takes a `subparser` as input and calls it after every instance of
@@ -553,9 +1418,323 @@ impl<'i> Parser<'i> {
         self.parse_comma_separated(Self::parse_expression, false)
     }
 
+    /// Parses the rest of a `SELECT` statement once the `SELECT` keyword has
+    /// been consumed. The statement terminator is not consumed, so this also
+    /// works for subqueries.
+    fn parse_select(&mut self) -> ParseResult<Statement> {
+        self.parse_select_with_ctes(vec![])
+    }
+
+    /// Same as [`Self::parse_select`] but attaches the CTEs previously parsed
+    /// from the `WITH` clause to the resulting statement.
+    fn parse_select_with_ctes(&mut self, ctes: Vec<Cte>) -> ParseResult<Statement> {
+        let distinct = self.consume_optional_keyword(Keyword::Distinct);
+        let columns = self.parse_comma_separated(Self::parse_select_item, false)?;
+
+        // SELECT 1 + 1 doesn't need a table, but it can still have a WHERE
+        // clause like SELECT 1 WHERE 2 > 1.
+        let (from, joins) = if self.consume_optional_keyword(Keyword::From) {
+            (Some(self.parse_table_reference()?), self.parse_joins()?)
+        } else {
+            (None, vec![])
+        };
+
+        let r#where = self.parse_optional_where()?;
+
+        let group_by = self.parse_optional_group_by()?;
+        let having = self.parse_optional_having(&group_by)?;
+        if !group_by.is_empty() {
+            self.reject_misplaced_clause(Keyword::Where, "WHERE", "GROUP BY")?;
+        }
+
+        let order_by = self.parse_optional_order_by()?;
+        if !order_by.is_empty() {
+            self.reject_misplaced_clause(Keyword::Group, "GROUP BY", "ORDER BY")?;
+        }
+
+        let limit = self.parse_optional_limit()?;
+        let offset = self.parse_optional_offset()?;
+
+        Ok(Statement::Select(Box::new(Select {
+            ctes,
+            distinct,
+            columns,
+            from,
+            joins,
+            r#where,
+            group_by,
+            having,
+            order_by,
+            limit,
+            offset,
+        })))
+    }
+
+    /// Parses a query that starts with `keyword`, which must be either
+    /// `SELECT` or `WITH` and must be consumed before calling this function.
+    ///
+    /// The query can be followed by set operations like `UNION`. Statements,
+    /// views, `INSERT ... SELECT`, CTE bodies, subqueries and derived tables
+    /// all accept the same syntax.
+    fn parse_query(&mut self, keyword: Keyword) -> ParseResult<Statement> {
+        self.recurse(|parser| {
+            let ctes = match keyword {
+                Keyword::With => {
+                    let ctes = parser.parse_comma_separated(Self::parse_cte, false)?;
+                    parser.expect_keyword(Keyword::Select)?;
+                    ctes
+                }
+
+                _ => vec![],
+            };
+
+            let select = parser.parse_select_with_ctes(ctes)?;
+            parser.parse_set_operations(select)
+        })
+    }
+
+    /// Parses a single `name AS (SELECT ...)` definition of the `WITH` clause.
+    fn parse_cte(&mut self) -> ParseResult<Cte> {
+        let name = self.parse_identifier()?;
+        self.expect_keyword(Keyword::As)?;
+        self.expect_token(Token::LeftParen)?;
+        let keyword = self.expect_one_of(&[Keyword::Select, Keyword::With])?;
+        let query = Box::new(self.parse_query(keyword)?);
+        self.expect_token(Token::RightParen)?;
+
+        Ok(Cte { name, query })
+    }
+
+    /// Combines the given `SELECT` statement with the ones that follow it
+    /// through `UNION`, `INTERSECT` or `EXCEPT`.
+    ///
+    /// All the operators are left associative but `INTERSECT` has higher
+    /// precedence than `UNION` and `EXCEPT`, so this:
+    ///
+    /// ```sql
+    /// SELECT a FROM t1 UNION SELECT b FROM t2 INTERSECT SELECT c FROM t3;
+    /// ```
+    ///
+    /// is parsed as `t1 UNION (t2 INTERSECT t3)`.
+    fn parse_set_operations(&mut self, select: Statement) -> ParseResult<Statement> {
+        let mut left = self.parse_intersections(select)?;
+
+        loop {
+            let op = match self.peek_token() {
+                Some(Ok(Token::Keyword(Keyword::Union))) => SetOp::Union,
+                Some(Ok(Token::Keyword(Keyword::Except))) => SetOp::Except,
+                _ => break,
+            };
+
+            let _ = self.next_token();
+            let all = self.consume_optional_keyword(Keyword::All);
+            self.expect_keyword(Keyword::Select)?;
+            let select = self.parse_select()?;
+            let right = self.parse_intersections(select)?;
+
+            left = Statement::SetOperation {
+                left: Box::new(left),
+                op,
+                all,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// Folds a chain of `SELECT ... INTERSECT SELECT ...` statements.
+    fn parse_intersections(&mut self, select: Statement) -> ParseResult<Statement> {
+        let mut left = select;
+
+        while self.consume_optional_keyword(Keyword::Intersect) {
+            let all = self.consume_optional_keyword(Keyword::All);
+            self.expect_keyword(Keyword::Select)?;
+            let right = self.parse_select()?;
+
+            left = Statement::SetOperation {
+                left: Box::new(left),
+                op: SetOp::Intersect,
+                all,
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// Parses one expression of the `SELECT` column list followed by its
+    /// optional alias, which can be written with or without `AS`.
+    fn parse_select_item(&mut self) -> ParseResult<SelectItem> {
+        let expr = self.parse_expression()?;
+
+        let alias = match self.peek_token() {
+            Some(Ok(Token::Keyword(Keyword::As))) => {
+                let _ = self.next_token();
+                Some(self.parse_identifier()?)
+            }
+
+            Some(Ok(Token::Identifier(_))) => Some(self.parse_identifier()?),
+
+            _ => None,
+        };
+
+        Ok(SelectItem { expr, alias })
+    }
+
     /// Used to parse `CREATE TABLE` column definitions.
-    fn parse_column_definitions(&mut self) -> ParseResult<Vec<Column>> {
-        self.parse_comma_separated(Self::parse_column, true)
+    ///
+    /// Table level constraints like `PRIMARY KEY (id)` can be mixed with column
+    /// definitions. If they only apply to one column they are moved into the
+    /// [`Column::constraints`] of the column they reference, otherwise they
+    /// are returned as [`TableConstraint`] instances. Table level `CHECK`
+    /// constraints are always returned as [`TableConstraint::Check`] since
+    /// they can reference any column.
+    fn parse_column_definitions(&mut self) -> ParseResult<(Vec<Column>, Vec<TableConstraint>)> {
+        let mut columns = Vec::new();
+        let mut column_constraints = Vec::new();
+        let mut table_constraints = Vec::new();
+        let mut primary_keys = 0;
+
+        self.parse_comma_separated(
+            |parser| {
+                match parser.consume_one_of(&[
+                    Keyword::Primary,
+                    Keyword::Unique,
+                    Keyword::Foreign,
+                    Keyword::Check,
+                ]) {
+                    Keyword::None => {
+                        let start = parser.peek_location();
+                        let column = parser.parse_column()?;
+
+                        if columns
+                            .iter()
+                            .any(|col: &Column| col.name.eq_ignore_ascii_case(&column.name))
+                        {
+                            return Err(parser.error_from(
+                                start,
+                                ErrorKind::Other(format!(
+                                    "column '{}' specified more than once",
+                                    column.name
+                                )),
+                            ));
+                        }
+
+                        if column.constraints.contains(&Constraint::PrimaryKey) {
+                            primary_keys += 1;
+                            if primary_keys > 1 {
+                                return Err(parser.error_from(
+                                    start,
+                                    ErrorKind::Other(
+                                        "only one primary key per table is allowed".into(),
+                                    ),
+                                ));
+                            }
+                        }
+
+                        columns.push(column);
+                    }
+
+                    Keyword::Check => {
+                        table_constraints.push(TableConstraint::Check(parser.parse_check()?))
+                    }
+
+                    Keyword::Primary => {
+                        let start = parser.location;
+                        parser.expect_keyword(Keyword::Key)?;
+                        let mut key = parser.parse_identifier_list()?;
+
+                        primary_keys += 1;
+                        if primary_keys > 1 {
+                            return Err(parser.error_from(
+                                start,
+                                ErrorKind::Other(
+                                    "only one primary key per table is allowed".into(),
+                                ),
+                            ));
+                        }
+
+                        if key.len() == 1 {
+                            column_constraints.push((key.remove(0), Constraint::PrimaryKey));
+                        } else {
+                            table_constraints.push(TableConstraint::PrimaryKey(key));
+                        }
+                    }
+
+                    keyword => column_constraints.push(parser.parse_table_constraint(keyword)?),
+                };
+
+                Ok(())
+            },
+            true,
+        )?;
+
+        if columns.is_empty() {
+            return Err(self.error(ErrorKind::Other(
+                "tables must have at least one column".into(),
+            )));
+        }
+
+        for (name, constraint) in column_constraints {
+            let Some(column) = columns
+                .iter_mut()
+                .find(|col| col.name.eq_ignore_ascii_case(&name))
+            else {
+                return Err(self.error(ErrorKind::Other(format!(
+                    "table constraint references unknown column {name}"
+                ))));
+            };
+
+            column.constraints.push(constraint);
+        }
+
+        Ok((columns, table_constraints))
+    }
+
+    /// Parses single column table constraints like `FOREIGN KEY (col)
+    /// REFERENCES other(id)` once the first `keyword` has been consumed.
+    ///
+    /// Returns the name of the column the constraint applies to.
+    fn parse_table_constraint(&mut self, keyword: Keyword) -> ParseResult<(String, Constraint)> {
+        if keyword == Keyword::Foreign {
+            self.expect_keyword(Keyword::Key)?;
+        }
+
+        self.expect_token(Token::LeftParen)?;
+        let column = self.parse_identifier()?;
+        self.expect_token(Token::RightParen)?;
+
+        let constraint = match keyword {
+            Keyword::Unique => Constraint::Unique,
+            Keyword::Foreign => {
+                self.expect_keyword(Keyword::References)?;
+                self.parse_references()?
+            }
+            _ => unreachable!(),
+        };
+
+        Ok((column, constraint))
+    }
+
+    /// Parses the `(expr)` part of `CHECK (expr)`.
+    fn parse_check(&mut self) -> ParseResult<Expression> {
+        self.expect_token(Token::LeftParen)?;
+        let expr = self.parse_expression()?;
+        self.expect_token(Token::RightParen)?;
+
+        Ok(expr)
+    }
+
+    /// Parses the `other(id)` part of `REFERENCES other(id)`.
+    fn parse_references(&mut self) -> ParseResult<Constraint> {
+        let table = self.parse_identifier()?;
+
+        self.expect_token(Token::LeftParen)?;
+        let column = self.parse_identifier()?;
+        self.expect_token(Token::RightParen)?;
+
+        Ok(Constraint::References { table, column })
     }
 
     /// Expects a list of identifiers, not complete expressions.
@@ -587,42 +1766,253 @@ impl<'i> Parser<'i> {
     /// clause:
     ///
     /// ```sql
-    /// SELECT * FROM table WHERE condition;
     /// UPDATE table SET column = "value" WHERE condition;
     /// DELETE FROM table WHERE condition;
     /// ```
-    fn parse_from_and_optional_where(&mut self) -> ParseResult<(String, Option<Expression>)> {
-        let from = self.parse_identifier()?;
+    fn parse_from_and_optional_where(
+        &mut self,
+    ) -> ParseResult<(TableReference, Option<Expression>)> {
+        let from = self.parse_table_reference()?;
         let r#where = self.parse_optional_where()?;
 
         Ok((from, r#where))
     }
 
-    /// Parses the `ORDER BY` clause at the end of `SELECT` statements.
-    ///
-    /// It only works with identifiers (not expressions) for now.
-    fn parse_optional_order_by(&mut self) -> ParseResult<Vec<Expression>> {
-        if self.consume_optional_keyword(Keyword::Order) {
-            self.expect_keyword(Keyword::By)?;
+    /// Parses the `RETURNING` clause that can follow `INSERT`, `UPDATE` and
+    /// `DELETE` statements. `RETURNING *` parses as [`Expression::Wildcard`].
+    fn parse_optional_returning(&mut self) -> ParseResult<Vec<Expression>> {
+        if self.consume_optional_keyword(Keyword::Returning) {
             self.parse_comma_separated_expressions()
         } else {
             Ok(Vec::new())
         }
     }
 
-    /// Same as [`Self::expect_token`] but takes [`Keyword`] variants instead.
-    fn expect_keyword(&mut self, expected: Keyword) -> ParseResult<Keyword> {
-        self.expect_token(Token::Keyword(expected))
-            .map(|_| expected)
-    }
-
-    /// Automatically fails if the `expected` token is not the next one in the
-    /// stream (after whitespaces).
+    /// Parses a table name followed by its optional alias, which can be written
+    /// with or without `AS`. Keywords such as `WHERE` or `ORDER` are never
+    /// identifiers, so the alias can't swallow the next clause.
     ///
-    /// If it is, it will be returned back.
-    fn expect_token(&mut self, expected: Token) -> ParseResult<Token> {
-        self.next_token().and_then(|token| {
-            if token == expected {
+    /// Derived tables like `(SELECT ...) AS alias` are also parsed here, but
+    /// their alias is required.
+    fn parse_table_reference(&mut self) -> ParseResult<TableReference> {
+        if self.consume_optional_token(Token::LeftParen) {
+            let keyword = self.expect_one_of(&[Keyword::Select, Keyword::With])?;
+            let query = Box::new(self.recurse(|parser| parser.parse_query(keyword))?);
+            self.expect_token(Token::RightParen)?;
+
+            self.consume_optional_keyword(Keyword::As);
+
+            let Some(Ok(Token::Identifier(_))) = self.peek_token() else {
+                return Err(self.error(ErrorKind::Other(
+                    "subqueries in FROM must have an alias".into(),
+                )));
+            };
+
+            let alias = self.parse_identifier()?;
+
+            return Ok(TableReference::Subquery { query, alias });
+        }
+
+        let mut database = None;
+        let mut name = self.parse_identifier()?;
+
+        if self.consume_optional_token(Token::Dot) {
+            database = Some(name);
+            name = self.parse_identifier()?;
+        }
+
+        let alias = match self.peek_token() {
+            Some(Ok(Token::Keyword(Keyword::As))) => {
+                let _ = self.next_token();
+                Some(self.parse_identifier()?)
+            }
+
+            Some(Ok(Token::Identifier(_))) => Some(self.parse_identifier()?),
+
+            _ => None,
+        };
+
+        Ok(TableReference::Table {
+            database,
+            name,
+            alias,
+        })
+    }
+
+    /// Parses all the `[INNER | LEFT | RIGHT | FULL] JOIN table ON condition`
+    /// clauses that follow the `FROM` table in `SELECT` statements.
+    fn parse_joins(&mut self) -> ParseResult<Vec<Join>> {
+        let mut joins = Vec::new();
+
+        while let Some(join_type) = self.parse_optional_join_type()? {
+            let table = self.parse_table_reference()?;
+            self.expect_keyword(Keyword::On)?;
+            let on = self.parse_expression()?;
+
+            joins.push(Join {
+                join_type,
+                table,
+                on,
+            });
+        }
+
+        Ok(joins)
+    }
+
+    /// Consumes the keywords that start a `JOIN` clause if there's one.
+    ///
+    /// `OUTER` is optional and doesn't change anything, `LEFT JOIN` and
+    /// `LEFT OUTER JOIN` are the same.
+    fn parse_optional_join_type(&mut self) -> ParseResult<Option<JoinType>> {
+        let join_type = match self.peek_token() {
+            Some(Ok(Token::Keyword(Keyword::Join))) => {
+                let _ = self.next_token();
+                return Ok(Some(JoinType::Inner));
+            }
+
+            Some(Ok(Token::Keyword(Keyword::Inner))) => JoinType::Inner,
+            Some(Ok(Token::Keyword(Keyword::Left))) => JoinType::Left,
+            Some(Ok(Token::Keyword(Keyword::Right))) => JoinType::Right,
+            Some(Ok(Token::Keyword(Keyword::Full))) => JoinType::Full,
+
+            _ => return Ok(None),
+        };
+
+        let _ = self.next_token();
+
+        if join_type != JoinType::Inner {
+            self.consume_optional_keyword(Keyword::Outer);
+        }
+
+        self.expect_keyword(Keyword::Join)?;
+
+        Ok(Some(join_type))
+    }
+
+    /// Parses the `GROUP BY` clause that follows `WHERE` in `SELECT`
+    /// statements.
+    fn parse_optional_group_by(&mut self) -> ParseResult<Vec<Expression>> {
+        if self.consume_optional_keyword(Keyword::Group) {
+            self.expect_keyword(Keyword::By)?;
+            self.parse_comma_separated_expressions()
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Parses the `HAVING` clause that filters the groups produced by
+    /// `GROUP BY`.
+    ///
+    /// `HAVING` without `GROUP BY` is not allowed.
+    fn parse_optional_having(
+        &mut self,
+        group_by: &[Expression],
+    ) -> ParseResult<Option<Expression>> {
+        if !self.consume_optional_keyword(Keyword::Having) {
+            return Ok(None);
+        }
+
+        if group_by.is_empty() {
+            return Err(self.error(ErrorKind::Other(
+                "HAVING clause requires a GROUP BY clause".into(),
+            )));
+        }
+
+        Ok(Some(self.parse_expression()?))
+    }
+
+    /// Fails if the next token is the `misplaced` keyword, which means that
+    /// `clause` was written after `before` instead of preceding it. Something
+    /// like this:
+    ///
+    /// ```sql
+    /// SELECT * FROM users ORDER BY name GROUP BY age;
+    /// ```
+    fn reject_misplaced_clause(
+        &mut self,
+        misplaced: Keyword,
+        clause: &str,
+        before: &str,
+    ) -> ParseResult<()> {
+        if self.consume_optional_keyword(misplaced) {
+            return Err(self.error(ErrorKind::Other(format!(
+                "{clause} clause must appear before {before}"
+            ))));
+        }
+
+        Ok(())
+    }
+
+    /// Parses the `ORDER BY` clause at the end of `SELECT` statements.
+    fn parse_optional_order_by(&mut self) -> ParseResult<Vec<OrderBy>> {
+        if self.consume_optional_keyword(Keyword::Order) {
+            self.expect_keyword(Keyword::By)?;
+            self.parse_comma_separated(Self::parse_order_by_key, false)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Parses one sort key like `age DESC NULLS LAST`. Both the direction and
+    /// the nulls ordering are optional.
+    fn parse_order_by_key(&mut self) -> ParseResult<OrderBy> {
+        let expr = self.parse_expression()?;
+
+        let direction = match self.consume_one_of(&[Keyword::Asc, Keyword::Desc]) {
+            Keyword::Desc => Direction::Desc,
+            _ => Direction::Asc,
+        };
+
+        let nulls = if self.consume_optional_keyword(Keyword::Nulls) {
+            match self.expect_one_of(&[Keyword::First, Keyword::Last])? {
+                Keyword::First => Some(NullsOrder::First),
+                _ => Some(NullsOrder::Last),
+            }
+        } else {
+            None
+        };
+
+        Ok(OrderBy {
+            expr,
+            direction,
+            nulls,
+        })
+    }
+
+    /// Parses the `LIMIT` clause that can follow `ORDER BY` in `SELECT`
+    /// statements.
+    fn parse_optional_limit(&mut self) -> ParseResult<Option<Expression>> {
+        if self.consume_optional_keyword(Keyword::Limit) {
+            Ok(Some(self.parse_expression()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parses the `OFFSET` clause at the very end of `SELECT` statements.
+    ///
+    /// `OFFSET` doesn't require a preceding `LIMIT`.
+    fn parse_optional_offset(&mut self) -> ParseResult<Option<Expression>> {
+        if self.consume_optional_keyword(Keyword::Offset) {
+            Ok(Some(self.parse_expression()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Same as [`Self::expect_token`] but takes [`Keyword`] variants instead.
+    fn expect_keyword(&mut self, expected: Keyword) -> ParseResult<Keyword> {
+        self.expect_token(Token::Keyword(expected))
+            .map(|_| expected)
+    }
+
+    /// Automatically fails if the `expected` token is not the next one in the
+    /// stream (after whitespaces).
+    ///
+    /// If it is, it will be returned back.
+    fn expect_token(&mut self, expected: Token) -> ParseResult<Token> {
+        self.next_token().and_then(|token| {
+            if token == expected {
                 Ok(token)
             } else {
                 Err(self.error(ErrorKind::Expected {
@@ -653,6 +2043,30 @@ impl<'i> Parser<'i> {
         }
     }
 
+    /// Same as [`Self::expect_one_of`] with [`Self::supported_statements`],
+    /// but if the statement starts with an identifier that looks like a typo
+    /// the error suggests the closest keyword instead.
+    fn expect_statement_keyword(&mut self) -> ParseResult<Keyword> {
+        let statements = Self::supported_statements();
+
+        self.expect_one_of(&statements).map_err(|mut error| {
+            if let ErrorKind::ExpectedOneOf {
+                found: Token::Identifier(ident),
+                ..
+            } = &error.kind
+            {
+                if let Some(suggestion) = closest_keyword(ident, &statements) {
+                    error.kind = ErrorKind::UnknownStatement {
+                        found: ident.clone(),
+                        suggestion,
+                    };
+                }
+            }
+
+            error
+        })
+    }
+
     /// Consumes all the tokens before and including the given `optional`
     /// keyword.
     ///
@@ -698,6 +2112,52 @@ impl<'i> Parser<'i> {
             kind,
             input: self.input.to_owned(),
             location: self.location,
+            end: self.end,
+        }
+    }
+
+    /// Same as [`Self::error`] but the error span starts at `start` instead
+    /// of the last token consumed.
+    fn error_from(&self, start: Location, kind: ErrorKind) -> ParserError {
+        ParserError {
+            location: start,
+            ..self.error(kind)
+        }
+    }
+
+    /// Expects the [`Token::SemiColon`] that terminates statements, unless
+    /// [`Self::allow_missing_final_semicolon`] is set and there's nothing
+    /// left to parse.
+    fn expect_semicolon(&mut self) -> ParseResult<()> {
+        if self.allow_missing_final_semicolon {
+            if let Some(Ok(Token::Eof)) = self.peek_token() {
+                return Ok(());
+            }
+        }
+
+        self.expect_token(Token::SemiColon)?;
+        Ok(())
+    }
+
+    /// Consumes stray semicolons found where a statement should start.
+    fn skip_empty_statements(&mut self) {
+        while self.consume_optional_token(Token::SemiColon) {}
+    }
+
+    /// Consumes tokens until a [`Token::SemiColon`] has been consumed or the
+    /// next token is [`Token::Eof`]. Used by [`Self::try_parse_all`] to skip
+    /// the rest of a statement that failed to parse.
+    #[cfg(test)]
+    fn recover_to_semicolon(&mut self) -> ParseResult<()> {
+        loop {
+            match self.peek_token() {
+                Some(Ok(Token::Eof)) | None => return Ok(()),
+                _ => {
+                    if self.next_token()? == Token::SemiColon {
+                        return Ok(());
+                    }
+                }
+            }
         }
     }
 
@@ -728,7 +2188,7 @@ impl<'i> Parser<'i> {
     }
 
     /// Consumes and returns the next [`Token`] in the stream updating
-    /// [`Self::location`] in the process.
+    /// [`Self::location`] and [`Self::end`] in the process.
     ///
     /// This removes the need to use [`TokenWithLocation`] instances. This
     /// method should not be called after [`Token::Eof`] has been returned once
@@ -740,11 +2200,22 @@ impl<'i> Parser<'i> {
             _ => {
                 let token = self.tokenizer.next().unwrap()?;
                 self.location = token.location;
+                self.end = token.end;
                 Ok(token.variant)
             }
         }
     }
 
+    /// Returns the [`Location`] where the next relevant [`Token`] starts
+    /// without consuming it, or [`Self::end`] if there are no more tokens.
+    fn peek_location(&mut self) -> Location {
+        self.skip_white_spaces();
+        match self.tokenizer.peek() {
+            Some(Ok(token)) => token.location,
+            _ => self.end,
+        }
+    }
+
     /// Same as [`Self::next_token_in_stream`] but does not consume the next
     /// token.
     fn peek_token_in_stream(&mut self) -> Option<Result<&Token, &TokenizerError>> {
@@ -773,10 +2244,19 @@ impl<'i> Parser<'i> {
             Keyword::Insert,
             Keyword::Delete,
             Keyword::Drop,
+            Keyword::Truncate,
+            Keyword::Alter,
             Keyword::Start,
+            Keyword::Begin,
+            Keyword::Savepoint,
             Keyword::Rollback,
             Keyword::Commit,
             Keyword::Explain,
+            Keyword::Show,
+            Keyword::Describe,
+            Keyword::Desc,
+            Keyword::Use,
+            Keyword::With,
         ]
     }
 
@@ -786,10 +2266,42 @@ impl<'i> Parser<'i> {
         // Specifying the initial keyword (INT, BIGINT) takes care of the
         // optional UNSIGNED that follows.
         vec![
+            Keyword::SmallInt,
             Keyword::Int,
             Keyword::BigInt,
             Keyword::Bool,
             Keyword::Varchar,
+            Keyword::Text,
+            Keyword::Float,
+            Keyword::Real,
+            Keyword::Double,
+            Keyword::Decimal,
+            Keyword::Numeric,
+            Keyword::Date,
+            Keyword::Timestamp,
+        ]
+    }
+
+    /// Keywords that can also be used as identifiers. See
+    /// [`Self::keyword_as_identifier`].
+    ///
+    /// These only show up where the parser explicitly expects them, never in
+    /// places where an identifier could appear as well, so treating them as
+    /// identifiers is not ambiguous.
+    fn non_reserved_keywords() -> Vec<Keyword> {
+        vec![
+            Keyword::Key,
+            Keyword::Text,
+            Keyword::Date,
+            Keyword::Timestamp,
+            Keyword::Transaction,
+            Keyword::Begin,
+            Keyword::View,
+            Keyword::Tables,
+            Keyword::Columns,
+            Keyword::First,
+            Keyword::Last,
+            Keyword::Escape,
         ]
     }
 
@@ -800,6 +2312,11 @@ impl<'i> Parser<'i> {
             Token::Minus,
             Token::Div,
             Token::Mul,
+            Token::BitAnd,
+            Token::BitOr,
+            Token::BitXor,
+            Token::ShiftLeft,
+            Token::ShiftRight,
             Token::Eq,
             Token::Neq,
             Token::Gt,
@@ -808,10 +2325,88 @@ impl<'i> Parser<'i> {
             Token::LtEq,
             Token::Keyword(Keyword::And),
             Token::Keyword(Keyword::Or),
+            Token::Keyword(Keyword::Like),
+            Token::Keyword(Keyword::ILike),
+            Token::Keyword(Keyword::In),
+            Token::Keyword(Keyword::Not),
+            Token::Keyword(Keyword::Between),
+            Token::Keyword(Keyword::Is),
         ]
     }
 }
 
+/// Streaming iterator over the statements of a [`Parser`]. See
+/// [`Parser::statements`].
+pub(crate) struct Statements<'i> {
+    parser: Parser<'i>,
+    done: bool,
+}
+
+impl<'i> Iterator for Statements<'i> {
+    type Item = ParseResult<Statement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.parser.skip_empty_statements();
+
+        if let Some(Ok(Token::Eof)) | None = self.parser.peek_token() {
+            self.done = true;
+            return None;
+        }
+
+        let result = self.parser.parse_statement();
+        self.done = result.is_err();
+
+        Some(result)
+    }
+}
+
+/// Maximum [`levenshtein_distance`] for an identifier to be considered a
+/// misspelled keyword.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Returns the keyword in `keywords` closest to `ident` if it's within
+/// [`MAX_SUGGESTION_DISTANCE`]. Ties are resolved in favour of the keyword that
+/// comes first.
+fn closest_keyword(ident: &str, keywords: &[Keyword]) -> Option<Keyword> {
+    let ident = ident.to_uppercase();
+
+    keywords
+        .iter()
+        .map(|keyword| (levenshtein_distance(&ident, &keyword.to_string()), *keyword))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, keyword)| keyword)
+}
+
+/// Minimum number of single character insertions, deletions or substitutions
+/// required to turn `a` into `b`.
+///
+/// Classic dynamic programming algorithm that only keeps the previous row of
+/// the matrix in memory. See [Levenshtein distance].
+///
+/// [Levenshtein distance]: https://en.wikipedia.org/wiki/Levenshtein_distance
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_chr) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, b_chr) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_chr != *b_chr);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -822,82 +2417,937 @@ mod tests {
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Select {
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
                 columns: vec![
-                    Expression::Identifier("id".into()),
-                    Expression::Identifier("name".into())
+                    Expression::Identifier("id".into()).into(),
+                    Expression::Identifier("name".into()).into()
                 ],
-                from: "users".into(),
+                from: Some("users".into()),
+                joins: vec![],
                 r#where: None,
-                order_by: vec![]
-            })
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
         )
     }
 
+    #[test]
+    fn parse_select_without_from() {
+        let select = |columns| {
+            Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns,
+                from: None,
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+            }))
+        };
+
+        assert_eq!(
+            Parser::new("SELECT 1 + 1;").parse_statement(),
+            Ok(select(vec![Expression::BinaryOperation {
+                left: Box::new(Expression::Value(Value::Number(1))),
+                operator: BinaryOperator::Plus,
+                right: Box::new(Expression::Value(Value::Number(1))),
+            }
+            .into()]))
+        );
+
+        assert_eq!(
+            Parser::new("SELECT \"hello\";").parse_statement(),
+            Ok(select(vec![Expression::Value(Value::String(
+                "hello".into()
+            ))
+            .into()]))
+        );
+
+        assert_eq!(
+            Parser::new("SELECT 1;;").try_parse(),
+            Ok(vec![select(vec![
+                Expression::Value(Value::Number(1)).into()
+            ])])
+        );
+    }
+
+    #[test]
+    fn parse_select_without_from_with_where() {
+        assert_eq!(
+            Parser::new("SELECT 1 WHERE 2 > 1 ORDER BY 1;").parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Value(Value::Number(1)).into()],
+                from: None,
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Value(Value::Number(2))),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![Expression::Value(Value::Number(1)).into()],
+                limit: None,
+                offset: None,
+            })))
+        );
+    }
+
     #[test]
     fn parse_select_wildcard() {
         let sql = "SELECT * FROM users;";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Select {
-                columns: vec![Expression::Wildcard],
-                from: "users".into(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("users".into()),
+                joins: vec![],
                 r#where: None,
-                order_by: vec![]
-            })
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
         )
     }
 
     #[test]
-    fn parse_select_where() {
-        let sql = "SELECT id, price, discount FROM products WHERE price >= 100;";
+    fn parse_select_with_alias() {
+        let sql = "SELECT price * qty AS total FROM orders;";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Select {
-                columns: vec![
-                    Expression::Identifier("id".into()),
-                    Expression::Identifier("price".into()),
-                    Expression::Identifier("discount".into())
-                ],
-                from: "products".into(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![SelectItem {
+                    expr: Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("price".into())),
+                        operator: BinaryOperator::Mul,
+                        right: Box::new(Expression::Identifier("qty".into())),
+                    },
+                    alias: Some("total".into()),
+                }],
+                from: Some("orders".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_with_implicit_alias() {
+        let sql = "SELECT id, name username FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("id".into()).into(), SelectItem {
+                    expr: Expression::Identifier("name".into()),
+                    alias: Some("username".into()),
+                }],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_from_table_alias() {
+        for (sql, alias) in [
+            ("SELECT * FROM users;", None),
+            ("SELECT * FROM users u;", Some("u")),
+            ("SELECT * FROM users AS u;", Some("u")),
+        ] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Ok(Statement::Select(Box::new(Select {
+                    ctes: vec![],
+                    distinct: false,
+                    columns: vec![Expression::Wildcard.into()],
+                    from: Some(TableReference::Table {
+                        database: None,
+                        name: "users".into(),
+                        alias: alias.map(String::from),
+                    }),
+                    joins: vec![],
+                    r#where: None,
+                    group_by: vec![],
+                    having: None,
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                })))
+            )
+        }
+    }
+
+    #[test]
+    fn parse_table_alias_followed_by_where() {
+        let sql = "DELETE FROM users u WHERE id = 1;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Delete {
+                from: TableReference::Table {
+                    database: None,
+                    name: "users".into(),
+                    alias: Some("u".into()),
+                },
                 r#where: Some(Expression::BinaryOperation {
-                    left: Box::new(Expression::Identifier("price".into())),
-                    operator: BinaryOperator::GtEq,
-                    right: Box::new(Expression::Value(Value::Number(100)))
+                    left: Box::new(Expression::Identifier("id".into())),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Number(1))),
                 }),
-                order_by: vec![]
+                returning: vec![],
             })
         )
     }
 
     #[test]
-    fn parse_select_with_expressions() {
-        let sql = r#"
-            SELECT id, price, discount, price * discount / 100
-            FROM products
-            WHERE 100 <= price AND price < 1000 OR discount < 10 + (2 * 20);
-        "#;
+    fn parse_table_alias_followed_by_order_by() {
+        let sql = "SELECT id FROM users u ORDER BY id;";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Select {
-                columns: vec![
-                    Expression::Identifier("id".into()),
-                    Expression::Identifier("price".into()),
-                    Expression::Identifier("discount".into()),
-                    Expression::BinaryOperation {
-                        left: Box::new(Expression::BinaryOperation {
-                            left: Box::new(Expression::Identifier("price".into())),
-                            operator: BinaryOperator::Mul,
-                            right: Box::new(Expression::Identifier("discount".into())),
-                        }),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("id".into()).into()],
+                from: Some(TableReference::Table {
+                    database: None,
+                    name: "users".into(),
+                    alias: Some("u".into()),
+                }),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![Expression::Identifier("id".into()).into()],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_qualified_identifiers() {
+        let sql = "SELECT users.id, users.* FROM users WHERE users.id = 1;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![
+                    Expression::QualifiedIdentifier {
+                        table: "users".into(),
+                        column: "id".into(),
+                    }
+                    .into(),
+                    Expression::QualifiedWildcard("users".into()).into(),
+                ],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::QualifiedIdentifier {
+                        table: "users".into(),
+                        column: "id".into(),
+                    }),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_inner_join() {
+        let sql = "SELECT * FROM users u INNER JOIN orders o ON u.id = o.user_id;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some(TableReference::Table {
+                    database: None,
+                    name: "users".into(),
+                    alias: Some("u".into()),
+                }),
+                joins: vec![Join {
+                    join_type: JoinType::Inner,
+                    table: TableReference::Table {
+                        database: None,
+                        name: "orders".into(),
+                        alias: Some("o".into()),
+                    },
+                    on: Expression::BinaryOperation {
+                        left: Box::new(Expression::QualifiedIdentifier {
+                            table: "u".into(),
+                            column: "id".into(),
+                        }),
+                        operator: BinaryOperator::Eq,
+                        right: Box::new(Expression::QualifiedIdentifier {
+                            table: "o".into(),
+                            column: "user_id".into(),
+                        }),
+                    },
+                }],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_chained_joins() {
+        let sql = "SELECT * FROM users JOIN orders ON users.id = orders.user_id JOIN products ON orders.product_id = products.id WHERE users.id = 1;";
+
+        let qualified = |table: &str, column: &str| {
+            Box::new(Expression::QualifiedIdentifier {
+                table: table.into(),
+                column: column.into(),
+            })
+        };
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("users".into()),
+                joins: vec![
+                    Join {
+                        join_type: JoinType::Inner,
+                        table: "orders".into(),
+                        on: Expression::BinaryOperation {
+                            left: qualified("users", "id"),
+                            operator: BinaryOperator::Eq,
+                            right: qualified("orders", "user_id"),
+                        },
+                    },
+                    Join {
+                        join_type: JoinType::Inner,
+                        table: "products".into(),
+                        on: Expression::BinaryOperation {
+                            left: qualified("orders", "product_id"),
+                            operator: BinaryOperator::Eq,
+                            right: qualified("products", "id"),
+                        },
+                    },
+                ],
+                r#where: Some(Expression::BinaryOperation {
+                    left: qualified("users", "id"),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_outer_joins() {
+        for (sql, join_type) in [
+            (
+                "SELECT * FROM users JOIN orders ON id = user_id;",
+                JoinType::Inner,
+            ),
+            (
+                "SELECT * FROM users INNER JOIN orders ON id = user_id;",
+                JoinType::Inner,
+            ),
+            (
+                "SELECT * FROM users LEFT JOIN orders ON id = user_id;",
+                JoinType::Left,
+            ),
+            (
+                "SELECT * FROM users LEFT OUTER JOIN orders ON id = user_id;",
+                JoinType::Left,
+            ),
+            (
+                "SELECT * FROM users RIGHT JOIN orders ON id = user_id;",
+                JoinType::Right,
+            ),
+            (
+                "SELECT * FROM users RIGHT OUTER JOIN orders ON id = user_id;",
+                JoinType::Right,
+            ),
+            (
+                "SELECT * FROM users FULL JOIN orders ON id = user_id;",
+                JoinType::Full,
+            ),
+            (
+                "SELECT * FROM users FULL OUTER JOIN orders ON id = user_id;",
+                JoinType::Full,
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Ok(Statement::Select(Box::new(Select {
+                    ctes: vec![],
+                    distinct: false,
+                    columns: vec![Expression::Wildcard.into()],
+                    from: Some("users".into()),
+                    joins: vec![Join {
+                        join_type,
+                        table: "orders".into(),
+                        on: Expression::BinaryOperation {
+                            left: Box::new(Expression::Identifier("id".into())),
+                            operator: BinaryOperator::Eq,
+                            right: Box::new(Expression::Identifier("user_id".into())),
+                        },
+                    }],
+                    r#where: None,
+                    group_by: vec![],
+                    having: None,
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                }))),
+                "{sql}"
+            )
+        }
+    }
+
+    #[test]
+    fn parse_scalar_subquery_in_where() {
+        let sql = "SELECT * FROM products WHERE price > (SELECT AVG(price) FROM products);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("products".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Subquery(Box::new(Statement::Select(Box::new(
+                        Select {
+                            ctes: vec![],
+                            distinct: false,
+                            columns: vec![Expression::Function {
+                                name: "AVG".into(),
+                                distinct: false,
+                                args: vec![Expression::Identifier("price".into())],
+                            }
+                            .into()],
+                            from: Some("products".into()),
+                            joins: vec![],
+                            r#where: None,
+                            group_by: vec![],
+                            having: None,
+                            order_by: vec![],
+                            limit: None,
+                            offset: None
+                        }
+                    ))))),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_scalar_subquery_in_select_column() {
+        let sql = "SELECT id, (SELECT name FROM users WHERE id = 1) AS owner FROM products;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("id".into()).into(), SelectItem {
+                    expr: Expression::Subquery(Box::new(Statement::Select(Box::new(Select {
+                        ctes: vec![],
+                        distinct: false,
+                        columns: vec![Expression::Identifier("name".into()).into()],
+                        from: Some("users".into()),
+                        joins: vec![],
+                        r#where: Some(Expression::BinaryOperation {
+                            left: Box::new(Expression::Identifier("id".into())),
+                            operator: BinaryOperator::Eq,
+                            right: Box::new(Expression::Value(Value::Number(1))),
+                        }),
+                        group_by: vec![],
+                        having: None,
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    })))),
+                    alias: Some("owner".into()),
+                }],
+                from: Some("products".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_from_derived_table() {
+        let sql = "SELECT * FROM (SELECT id FROM users) AS sub;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some(TableReference::Subquery {
+                    query: Box::new(Statement::Select(Box::new(Select {
+                        ctes: vec![],
+                        distinct: false,
+                        columns: vec![Expression::Identifier("id".into()).into()],
+                        from: Some("users".into()),
+                        joins: vec![],
+                        r#where: None,
+                        group_by: vec![],
+                        having: None,
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }))),
+                    alias: "sub".into(),
+                }),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_common_table_expression() {
+        let sql = "WITH recent AS (SELECT id FROM orders WHERE id > 10) SELECT * FROM recent;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![Cte {
+                    name: "recent".into(),
+                    query: Box::new(Statement::Select(Box::new(Select {
+                        ctes: vec![],
+                        distinct: false,
+                        columns: vec![Expression::Identifier("id".into()).into()],
+                        from: Some("orders".into()),
+                        joins: vec![],
+                        r#where: Some(Expression::BinaryOperation {
+                            left: Box::new(Expression::Identifier("id".into())),
+                            operator: BinaryOperator::Gt,
+                            right: Box::new(Expression::Value(Value::Number(10))),
+                        }),
+                        group_by: vec![],
+                        having: None,
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }))),
+                }],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("recent".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_chained_common_table_expressions() {
+        let sql = "WITH a AS (SELECT id FROM users), b AS (SELECT id FROM a) SELECT id FROM b;";
+
+        let Ok(Statement::Select(select)) = Parser::new(sql).parse_statement() else {
+            panic!("failed to parse {sql}");
+        };
+        let Select { ctes, from, .. } = *select;
+
+        let select_id_from = |table: &str| {
+            Box::new(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("id".into()).into()],
+                from: Some(table.into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+            })))
+        };
+
+        assert_eq!(ctes, vec![
+            Cte {
+                name: "a".into(),
+                query: select_id_from("users"),
+            },
+            Cte {
+                name: "b".into(),
+                query: select_id_from("a"),
+            },
+        ]);
+        assert_eq!(from, Some("b".into()));
+    }
+
+    #[test]
+    fn parse_common_table_expressions_in_nested_queries() {
+        let select_id = |table: &str, ctes, r#where| {
+            Statement::Select(Box::new(Select {
+                ctes,
+                distinct: false,
+                columns: vec![Expression::Identifier("id".into()).into()],
+                from: Some(table.into()),
+                joins: vec![],
+                r#where,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+            }))
+        };
+
+        let with_a = || {
+            let cte = Cte {
+                name: "a".into(),
+                query: Box::new(select_id("users", vec![], None)),
+            };
+
+            Box::new(select_id("a", vec![cte], None))
+        };
+
+        assert_eq!(
+            Parser::new("CREATE VIEW v AS WITH a AS (SELECT id FROM users) SELECT id FROM a;")
+                .parse_statement(),
+            Ok(Statement::Create(Create::View {
+                name: "v".into(),
+                query: with_a(),
+            }))
+        );
+
+        let in_subquery = Expression::InSubquery {
+            expr: Box::new(Expression::Identifier("id".into())),
+            subquery: with_a(),
+            negated: false,
+        };
+
+        assert_eq!(
+            Parser::new(
+                "SELECT id FROM t WHERE id IN (WITH a AS (SELECT id FROM users) SELECT id FROM a);"
+            )
+            .parse_statement(),
+            Ok(select_id("t", vec![], Some(in_subquery)))
+        );
+    }
+
+    #[test]
+    fn parse_set_operations_in_subqueries() {
+        let select_id = |table: &str| {
+            Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("id".into()).into()],
+                from: Some(table.into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+            }))
+        };
+
+        let set_operation = |op| {
+            Box::new(Statement::SetOperation {
+                left: Box::new(select_id("users")),
+                op,
+                all: false,
+                right: Box::new(select_id("admins")),
+            })
+        };
+
+        let select_all = |from, r#where| {
+            Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some(from),
+                joins: vec![],
+                r#where,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+            }))
+        };
+
+        assert_eq!(
+            Parser::new(
+                "SELECT * FROM t WHERE id IN (SELECT id FROM users UNION SELECT id FROM admins);"
+            )
+            .parse_statement(),
+            Ok(select_all(
+                "t".into(),
+                Some(Expression::InSubquery {
+                    expr: Box::new(Expression::Identifier("id".into())),
+                    subquery: set_operation(SetOp::Union),
+                    negated: false,
+                })
+            ))
+        );
+
+        assert_eq!(
+            Parser::new(
+                "SELECT * FROM t WHERE EXISTS (SELECT id FROM users INTERSECT SELECT id FROM admins);"
+            )
+            .parse_statement(),
+            Ok(select_all(
+                "t".into(),
+                Some(Expression::Exists {
+                    subquery: set_operation(SetOp::Intersect),
+                    negated: false,
+                })
+            ))
+        );
+
+        assert_eq!(
+            Parser::new("SELECT * FROM (SELECT id FROM users EXCEPT SELECT id FROM admins) AS x;")
+                .parse_statement(),
+            Ok(select_all(
+                TableReference::Subquery {
+                    query: set_operation(SetOp::Except),
+                    alias: "x".into(),
+                },
+                None
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_union() {
+        for (sql, all) in [
+            ("SELECT id FROM users UNION SELECT id FROM admins;", false),
+            (
+                "SELECT id FROM users UNION ALL SELECT id FROM admins;",
+                true,
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Ok(Statement::SetOperation {
+                    left: Box::new(Statement::Select(Box::new(Select {
+                        ctes: vec![],
+                        distinct: false,
+                        columns: vec![Expression::Identifier("id".into()).into()],
+                        from: Some("users".into()),
+                        joins: vec![],
+                        r#where: None,
+                        group_by: vec![],
+                        having: None,
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }))),
+                    op: SetOp::Union,
+                    all,
+                    right: Box::new(Statement::Select(Box::new(Select {
+                        ctes: vec![],
+                        distinct: false,
+                        columns: vec![Expression::Identifier("id".into()).into()],
+                        from: Some("admins".into()),
+                        joins: vec![],
+                        r#where: None,
+                        group_by: vec![],
+                        having: None,
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }))),
+                })
+            )
+        }
+    }
+
+    #[test]
+    fn parse_chained_set_operations_precedence() {
+        let select = |table: &str| {
+            Box::new(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("id".into()).into()],
+                from: Some(table.into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+            })))
+        };
+
+        assert_eq!(
+            Parser::new("SELECT id FROM a UNION SELECT id FROM b EXCEPT SELECT id FROM c;")
+                .parse_statement(),
+            Ok(Statement::SetOperation {
+                left: Box::new(Statement::SetOperation {
+                    left: select("a"),
+                    op: SetOp::Union,
+                    all: false,
+                    right: select("b"),
+                }),
+                op: SetOp::Except,
+                all: false,
+                right: select("c"),
+            })
+        );
+
+        assert_eq!(
+            Parser::new("SELECT id FROM a UNION SELECT id FROM b INTERSECT SELECT id FROM c;")
+                .parse_statement(),
+            Ok(Statement::SetOperation {
+                left: select("a"),
+                op: SetOp::Union,
+                all: false,
+                right: Box::new(Statement::SetOperation {
+                    left: select("b"),
+                    op: SetOp::Intersect,
+                    all: false,
+                    right: select("c"),
+                }),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_select_where() {
+        let sql = "SELECT id, price, discount FROM products WHERE price >= 100;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![
+                    Expression::Identifier("id".into()).into(),
+                    Expression::Identifier("price".into()).into(),
+                    Expression::Identifier("discount".into()).into()
+                ],
+                from: Some("products".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::GtEq,
+                    right: Box::new(Expression::Value(Value::Number(100)))
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_with_expressions() {
+        let sql = r#"
+            SELECT id, price, discount, price * discount / 100
+            FROM products
+            WHERE 100 <= price AND price < 1000 OR discount < 10 + (2 * 20);
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![
+                    Expression::Identifier("id".into()).into(),
+                    Expression::Identifier("price".into()).into(),
+                    Expression::Identifier("discount".into()).into(),
+                    Expression::BinaryOperation {
+                        left: Box::new(Expression::BinaryOperation {
+                            left: Box::new(Expression::Identifier("price".into())),
+                            operator: BinaryOperator::Mul,
+                            right: Box::new(Expression::Identifier("discount".into())),
+                        }),
                         operator: BinaryOperator::Div,
                         right: Box::new(Expression::Value(Value::Number(100))),
                     }
+                    .into()
                 ],
-                from: "products".into(),
+                from: Some("products".into()),
+                joins: vec![],
                 r#where: Some(Expression::BinaryOperation {
                     left: Box::new(Expression::BinaryOperation {
                         left: Box::new(Expression::BinaryOperation {
@@ -929,8 +3379,12 @@ mod tests {
                         }),
                     })
                 }),
+                group_by: vec![],
+                having: None,
                 order_by: vec![],
-            })
+                limit: None,
+                offset: None,
+            })))
         )
     }
 
@@ -940,429 +3394,3962 @@ mod tests {
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Select {
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
                 columns: vec![
-                    Expression::Identifier("name".into()),
-                    Expression::Identifier("email".into())
+                    Expression::Identifier("name".into()).into(),
+                    Expression::Identifier("email".into()).into()
                 ],
-                from: "users".into(),
+                from: Some("users".into()),
+                joins: vec![],
                 r#where: None,
-                order_by: vec![Expression::Identifier("email".into())]
-            })
+                group_by: vec![],
+                having: None,
+                order_by: vec![Expression::Identifier("email".into()).into()],
+                limit: None,
+                offset: None
+            })))
         )
     }
 
     #[test]
-    fn parse_create_database() {
-        let sql = "CREATE DATABASE test;";
+    fn parse_order_by_direction_and_nulls() {
+        let sql = "SELECT * FROM users ORDER BY age DESC NULLS LAST, name ASC NULLS FIRST, id, email DESC;";
+
+        let Ok(Statement::Select(select)) = Parser::new(sql).parse_statement() else {
+            panic!("failed to parse {sql}");
+        };
+        let Select { order_by, .. } = *select;
+
+        assert_eq!(order_by, vec![
+            OrderBy {
+                expr: Expression::Identifier("age".into()),
+                direction: Direction::Desc,
+                nulls: Some(NullsOrder::Last),
+            },
+            OrderBy {
+                expr: Expression::Identifier("name".into()),
+                direction: Direction::Asc,
+                nulls: Some(NullsOrder::First),
+            },
+            OrderBy {
+                expr: Expression::Identifier("id".into()),
+                direction: Direction::Asc,
+                nulls: None,
+            },
+            OrderBy {
+                expr: Expression::Identifier("email".into()),
+                direction: Direction::Desc,
+                nulls: None,
+            },
+        ]);
+
+        assert_eq!(
+            order_by
+                .iter()
+                .map(OrderBy::nulls_first)
+                .collect::<Vec<_>>(),
+            [false, true, true, false]
+        );
+    }
+
+    #[test]
+    fn parse_order_by_nulls_without_position() {
+        let sql = "SELECT * FROM users ORDER BY age NULLS;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::ExpectedOneOf {
+                    expected: vec![
+                        Token::Keyword(Keyword::First),
+                        Token::Keyword(Keyword::Last)
+                    ],
+                    found: Token::SemiColon,
+                },
+                location: Location { line: 1, col: 39 },
+                end: Location { line: 1, col: 40 },
+                input: sql.into(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_select_distinct() {
+        let sql = "SELECT DISTINCT name FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: true,
+                columns: vec![Expression::Identifier("name".into()).into()],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_distinct_many() {
+        let sql = "SELECT DISTINCT country, city FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: true,
+                columns: vec![
+                    Expression::Identifier("country".into()).into(),
+                    Expression::Identifier("city".into()).into()
+                ],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_distinct_wildcard() {
+        let sql = "SELECT DISTINCT * FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: true,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_group_by() {
+        let sql = "SELECT age FROM users WHERE age > 18 GROUP BY age ORDER BY age;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("age".into()).into()],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("age".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(18))),
+                }),
+                group_by: vec![Expression::Identifier("age".into())],
+                having: None,
+                order_by: vec![Expression::Identifier("age".into()).into()],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_group_by_many() {
+        let sql = "SELECT country, city FROM users GROUP BY country, city;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![
+                    Expression::Identifier("country".into()).into(),
+                    Expression::Identifier("city".into()).into()
+                ],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![
+                    Expression::Identifier("country".into()),
+                    Expression::Identifier("city".into())
+                ],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_group_by_having() {
+        let sql = "SELECT age FROM users GROUP BY age HAVING age > 18;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("age".into()).into()],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![Expression::Identifier("age".into())],
+                having: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("age".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(18))),
+                }),
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_limit() {
+        let sql = "SELECT * FROM users LIMIT 10;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: Some(Expression::Value(Value::Number(10))),
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_limit_offset() {
+        let sql = "SELECT name FROM users ORDER BY name LIMIT 10 OFFSET 20;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Identifier("name".into()).into()],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![Expression::Identifier("name".into()).into()],
+                limit: Some(Expression::Value(Value::Number(10))),
+                offset: Some(Expression::Value(Value::Number(20)))
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_select_limit_expression() {
+        let sql = "SELECT * FROM users WHERE id > 5 LIMIT 5 + 5;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("users".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("id".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(5))),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Value(Value::Number(5))),
+                    operator: BinaryOperator::Plus,
+                    right: Box::new(Expression::Value(Value::Number(5))),
+                }),
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_create_database() {
+        let sql = "CREATE DATABASE test;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Database {
+                name: "test".into(),
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_database_if_not_exists() {
+        let sql = "CREATE DATABASE IF NOT EXISTS test;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Database {
+                name: "test".into(),
+                if_not_exists: true,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table() {
+        let sql = r#"
+            CREATE TABLE users (
+                id INT PRIMARY KEY,
+                name VARCHAR(255),
+                email VARCHAR(255) UNIQUE
+            );
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "users".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column::new("name", DataType::Varchar(255)),
+                    Column::unique("email", DataType::Varchar(255)),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_if_not_exists() {
+        let sql = "CREATE TABLE IF NOT EXISTS users (id INT PRIMARY KEY);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "users".into(),
+                columns: vec![Column::primary_key("id", DataType::Int)],
+                table_constraints: vec![],
+                if_not_exists: true,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_bool_columns() {
+        let sql = r#"
+            CREATE TABLE flags (
+                id INT PRIMARY KEY,
+                active BOOL,
+                verified BOOLEAN UNIQUE
+            );
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "flags".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column::new("active", DataType::Bool),
+                    Column::unique("verified", DataType::Bool),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_float_columns() {
+        let sql =
+            "CREATE TABLE products (id INT PRIMARY KEY, price FLOAT, weight REAL, ratio DOUBLE);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "products".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column::new("price", DataType::Float),
+                    Column::new("weight", DataType::Float),
+                    Column::new("ratio", DataType::Float),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_decimal_columns() {
+        let sql = "CREATE TABLE accounts (id INT PRIMARY KEY, balance DECIMAL(10, 2), rate NUMERIC(5,5));";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "accounts".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column::new("balance", DataType::Decimal {
+                        precision: 10,
+                        scale: 2
+                    }),
+                    Column::new("rate", DataType::Decimal {
+                        precision: 5,
+                        scale: 5
+                    }),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_integer_widths() {
+        let sql =
+            "CREATE TABLE stats (a SMALLINT, b INTEGER UNSIGNED, c BIGINT, d SMALLINT UNSIGNED);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "stats".into(),
+                columns: vec![
+                    Column::new("a", DataType::SmallInt),
+                    Column::new("b", DataType::UnsignedInt),
+                    Column::new("c", DataType::BigInt),
+                    Column::new("d", DataType::UnsignedSmallInt),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_date_columns() {
+        let sql = "CREATE TABLE events (id INT PRIMARY KEY, day DATE, created_at TIMESTAMP);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "events".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column::new("day", DataType::Date),
+                    Column::new("created_at", DataType::Timestamp),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_insert_date_strings() {
+        let sql = "INSERT INTO events (id, day, created_at) VALUES (1, '2024-01-31', '2024-01-31 12:30:00');";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "events".into(),
+                columns: ["id", "day", "created_at"].map(String::from).into(),
+                source: InsertSource::Values(vec![vec![
+                    Expression::Value(Value::Number(1)),
+                    Expression::Value(Value::String("2024-01-31".into())),
+                    Expression::Value(Value::String("2024-01-31 12:30:00".into())),
+                ]]),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_create_table_with_text_columns() {
+        let sql = r#"
+            CREATE TABLE posts (
+                slug TEXT PRIMARY KEY,
+                title VARCHAR(255),
+                body TEXT,
+                hash TEXT UNIQUE
+            );
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "posts".into(),
+                columns: vec![
+                    Column::primary_key("slug", DataType::Text),
+                    Column::new("title", DataType::Varchar(255)),
+                    Column::new("body", DataType::Text),
+                    Column::unique("hash", DataType::Text),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_default_values() {
+        let sql = r#"
+            CREATE TABLE users (
+                id INT PRIMARY KEY,
+                followers INT DEFAULT 0,
+                name VARCHAR(255) DEFAULT "unknown",
+                email VARCHAR(255) UNIQUE DEFAULT ""
+            );
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "users".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column {
+                        name: "followers".into(),
+                        data_type: DataType::Int,
+                        constraints: vec![Constraint::Default(Expression::Value(Value::Number(0)))],
+                    },
+                    Column {
+                        name: "name".into(),
+                        data_type: DataType::Varchar(255),
+                        constraints: vec![Constraint::Default(Expression::Value(Value::String(
+                            "unknown".into()
+                        )))],
+                    },
+                    Column {
+                        name: "email".into(),
+                        data_type: DataType::Varchar(255),
+                        constraints: vec![
+                            Constraint::Unique,
+                            Constraint::Default(Expression::Value(Value::String("".into()))),
+                        ],
+                    },
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_default_followed_by_other_constraints() {
+        let sql = "CREATE TABLE t (a INT DEFAULT 0 NOT NULL, b INT DEFAULT -1 + 2 PRIMARY KEY);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "t".into(),
+                columns: vec![
+                    Column {
+                        name: "a".into(),
+                        data_type: DataType::Int,
+                        constraints: vec![
+                            Constraint::Default(Expression::Value(Value::Number(0))),
+                            Constraint::NotNull,
+                        ],
+                    },
+                    Column {
+                        name: "b".into(),
+                        data_type: DataType::Int,
+                        constraints: vec![
+                            Constraint::Default(Expression::BinaryOperation {
+                                left: Box::new(Expression::UnaryOperation {
+                                    operator: UnaryOperator::Minus,
+                                    expr: Box::new(Expression::Value(Value::Number(1))),
+                                }),
+                                operator: BinaryOperator::Plus,
+                                right: Box::new(Expression::Value(Value::Number(2))),
+                            }),
+                            Constraint::PrimaryKey,
+                        ],
+                    },
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_not_null_columns() {
+        let sql = r#"
+            CREATE TABLE users (
+                id INT PRIMARY KEY NOT NULL,
+                name VARCHAR(255) NOT NULL,
+                email VARCHAR(255) NOT NULL UNIQUE
+            );
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "users".into(),
+                columns: vec![
+                    Column {
+                        name: "id".into(),
+                        data_type: DataType::Int,
+                        constraints: vec![Constraint::PrimaryKey, Constraint::NotNull],
+                    },
+                    Column {
+                        name: "name".into(),
+                        data_type: DataType::Varchar(255),
+                        constraints: vec![Constraint::NotNull],
+                    },
+                    Column {
+                        name: "email".into(),
+                        data_type: DataType::Varchar(255),
+                        constraints: vec![Constraint::NotNull, Constraint::Unique],
+                    },
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_references() {
+        let sql = "CREATE TABLE orders (id INT PRIMARY KEY, user_id INT REFERENCES users(id));";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "orders".into(),
+                columns: vec![Column::primary_key("id", DataType::Int), Column {
+                    name: "user_id".into(),
+                    data_type: DataType::Int,
+                    constraints: vec![Constraint::References {
+                        table: "users".into(),
+                        column: "id".into(),
+                    }],
+                },],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_table_constraints() {
+        let sql = r#"
+            CREATE TABLE orders (
+                id INT,
+                user_id INT,
+                code VARCHAR(16),
+                PRIMARY KEY (id),
+                FOREIGN KEY (user_id) REFERENCES users(id),
+                UNIQUE (code)
+            );
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "orders".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column {
+                        name: "user_id".into(),
+                        data_type: DataType::Int,
+                        constraints: vec![Constraint::References {
+                            table: "users".into(),
+                            column: "id".into(),
+                        }],
+                    },
+                    Column::unique("code", DataType::Varchar(16)),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_check_constraints() {
+        let sql = r#"
+            CREATE TABLE products (
+                id INT PRIMARY KEY,
+                price INT CHECK (price >= 0),
+                discount INT,
+                CHECK (discount <= price)
+            );
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "products".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column {
+                        name: "price".into(),
+                        data_type: DataType::Int,
+                        constraints: vec![Constraint::Check(Expression::BinaryOperation {
+                            left: Box::new(Expression::Identifier("price".into())),
+                            operator: BinaryOperator::GtEq,
+                            right: Box::new(Expression::Value(Value::Number(0))),
+                        })],
+                    },
+                    Column::new("discount", DataType::Int),
+                ],
+                table_constraints: vec![TableConstraint::Check(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("discount".into())),
+                    operator: BinaryOperator::LtEq,
+                    right: Box::new(Expression::Identifier("price".into())),
+                })],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_auto_increment() {
+        let sql =
+            "CREATE TABLE users (id INT PRIMARY KEY AUTO_INCREMENT, visits BIGINT AUTOINCREMENT);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "users".into(),
+                columns: vec![
+                    Column {
+                        name: "id".into(),
+                        data_type: DataType::Int,
+                        constraints: vec![Constraint::PrimaryKey, Constraint::AutoIncrement],
+                    },
+                    Column {
+                        name: "visits".into(),
+                        data_type: DataType::BigInt,
+                        constraints: vec![Constraint::AutoIncrement],
+                    },
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_composite_primary_key() {
+        let sql =
+            "CREATE TABLE follows (follower INT, followed INT, PRIMARY KEY (follower, followed));";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "follows".into(),
+                columns: vec![
+                    Column::new("follower", DataType::Int),
+                    Column::new("followed", DataType::Int),
+                ],
+                table_constraints: vec![TableConstraint::PrimaryKey(vec![
+                    "follower".into(),
+                    "followed".into()
+                ])],
+                if_not_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_index() {
+        let sql = "CREATE INDEX test_idx ON test(some_column);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Index {
+                name: "test_idx".into(),
+                table: "test".into(),
+                columns: vec!["some_column".into()],
+                unique: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_unique_index() {
+        let sql = "CREATE UNIQUE INDEX email_uq_idx ON users(email);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Index {
+                name: "email_uq_idx".into(),
+                table: "users".into(),
+                columns: vec!["email".into()],
+                unique: true,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_simple_update() {
+        let sql = "UPDATE users SET is_admin = 1;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Update {
+                table: "users".into(),
+                columns: vec![Assignment {
+                    identifier: "is_admin".into(),
+                    value: Expression::Value(Value::Number(1)),
+                }],
+                r#where: None,
+                returning: vec![],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_update_where() {
+        let sql = r#"
+            UPDATE products
+            SET price = price - 10, discount = 15, stock = 10
+            WHERE price > 100;
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Update {
+                table: "products".into(),
+                columns: vec![
+                    Assignment {
+                        identifier: "price".into(),
+                        value: Expression::BinaryOperation {
+                            left: Box::new(Expression::Identifier("price".into())),
+                            operator: BinaryOperator::Minus,
+                            right: Box::new(Expression::Value(Value::Number(10))),
+                        }
+                    },
+                    Assignment {
+                        identifier: "discount".into(),
+                        value: Expression::Value(Value::Number(15))
+                    },
+                    Assignment {
+                        identifier: "stock".into(),
+                        value: Expression::Value(Value::Number(10))
+                    }
+                ],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(100))),
+                }),
+                returning: vec![],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_delete_from() {
+        let sql = "DELETE FROM products;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Delete {
+                from: "products".into(),
+                r#where: None,
+                returning: vec![],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_delete_from_where() {
+        let sql = "DELETE FROM products WHERE price > 5000;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Delete {
+                from: "products".into(),
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(5000))),
+                }),
+                returning: vec![],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_delete_returning() {
+        let sql = "DELETE FROM products WHERE price > 5000 RETURNING id, name;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Delete {
+                from: "products".into(),
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(5000))),
+                }),
+                returning: vec![
+                    Expression::Identifier("id".into()),
+                    Expression::Identifier("name".into()),
+                ],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_update_returning() {
+        let sql = "UPDATE products SET price = price * 2 RETURNING id, price * 2;";
+
+        let price_times_two = Expression::BinaryOperation {
+            left: Box::new(Expression::Identifier("price".into())),
+            operator: BinaryOperator::Mul,
+            right: Box::new(Expression::Value(Value::Number(2))),
+        };
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Update {
+                table: "products".into(),
+                columns: vec![Assignment {
+                    identifier: "price".into(),
+                    value: price_times_two.clone(),
+                }],
+                r#where: None,
+                returning: vec![Expression::Identifier("id".into()), price_times_two],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_insert_returning_wildcard() {
+        let sql = "INSERT INTO users (id, name) VALUES (1, 'John') RETURNING *;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "users".into(),
+                columns: ["id", "name"].map(String::from).into(),
+                source: InsertSource::Values(vec![vec![
+                    Expression::Value(Value::Number(1)),
+                    Expression::Value(Value::String("John".into())),
+                ]]),
+                returning: vec![Expression::Wildcard],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_insert_into() {
+        let sql = r#"INSERT INTO users (id, name, email) VALUES (1, "Test", "test@test.com");"#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "users".into(),
+                columns: ["id", "name", "email"].map(String::from).into(),
+                source: InsertSource::Values(vec![vec![
+                    Expression::Value(Value::Number(1)),
+                    Expression::Value(Value::String("Test".into())),
+                    Expression::Value(Value::String("test@test.com".into())),
+                ]]),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_insert_multiple_rows() {
+        let sql = "INSERT INTO users (id, name) VALUES (1, 'John'), (2, 'Jane');";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "users".into(),
+                columns: ["id", "name"].map(String::from).into(),
+                source: InsertSource::Values(vec![
+                    vec![
+                        Expression::Value(Value::Number(1)),
+                        Expression::Value(Value::String("John".into())),
+                    ],
+                    vec![
+                        Expression::Value(Value::Number(2)),
+                        Expression::Value(Value::String("Jane".into())),
+                    ],
+                ]),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_insert_select() {
+        let sql = "INSERT INTO admins (id, name) SELECT id, name FROM users WHERE is_admin = TRUE;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "admins".into(),
+                columns: ["id", "name"].map(String::from).into(),
+                source: InsertSource::Select(Box::new(Statement::Select(Box::new(Select {
+                    ctes: vec![],
+                    distinct: false,
+                    columns: vec![
+                        Expression::Identifier("id".into()).into(),
+                        Expression::Identifier("name".into()).into(),
+                    ],
+                    from: Some("users".into()),
+                    joins: vec![],
+                    r#where: Some(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("is_admin".into())),
+                        operator: BinaryOperator::Eq,
+                        right: Box::new(Expression::Value(Value::Bool(true))),
+                    }),
+                    group_by: vec![],
+                    having: None,
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                })))),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_insert_floats() {
+        let sql = "INSERT INTO products (id, price, weight, ratio) VALUES (1, 9.99, 100., .5);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "products".into(),
+                columns: ["id", "price", "weight", "ratio"].map(String::from).into(),
+                source: InsertSource::Values(vec![vec![
+                    Expression::Value(Value::Number(1)),
+                    Expression::Value(Value::Float(9.99)),
+                    Expression::Value(Value::Float(100.0)),
+                    Expression::Value(Value::Float(0.5)),
+                ]]),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_insert_into_optional_columns() {
+        let sql = r#"INSERT INTO users VALUES (1, "Test", "test@test.com");"#;
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "users".into(),
+                columns: vec![],
+                source: InsertSource::Values(vec![vec![
+                    Expression::Value(Value::Number(1)),
+                    Expression::Value(Value::String("Test".into())),
+                    Expression::Value(Value::String("test@test.com".into())),
+                ]]),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_insert_multiple_rows_without_columns() {
+        let sql = "INSERT INTO users VALUES (1, 'John'), (2, 'Jane');";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "users".into(),
+                columns: vec![],
+                source: InsertSource::Values(vec![
+                    vec![
+                        Expression::Value(Value::Number(1)),
+                        Expression::Value(Value::String("John".into())),
+                    ],
+                    vec![
+                        Expression::Value(Value::Number(2)),
+                        Expression::Value(Value::String("Jane".into())),
+                    ],
+                ]),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_insert_select_without_columns() {
+        let sql = "INSERT INTO admins SELECT * FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "admins".into(),
+                columns: vec![],
+                source: InsertSource::Select(Box::new(Statement::Select(Box::new(Select {
+                    ctes: vec![],
+                    distinct: false,
+                    columns: vec![Expression::Wildcard.into()],
+                    from: Some("users".into()),
+                    joins: vec![],
+                    r#where: None,
+                    group_by: vec![],
+                    having: None,
+                    order_by: vec![],
+                    limit: None,
+                    offset: None
+                })))),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_insert_null() {
+        let sql = "INSERT INTO users (id, email) VALUES (1, NULL);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Insert {
+                into: "users".into(),
+                columns: ["id", "email"].map(String::from).into(),
+                source: InsertSource::Values(vec![vec![
+                    Expression::Value(Value::Number(1)),
+                    Expression::Value(Value::Null),
+                ]]),
+                returning: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_null_comparison() {
+        let sql = "DELETE FROM users WHERE email = null;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Delete {
+                from: "users".into(),
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("email".into())),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Null)),
+                }),
+                returning: vec![],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_bool_literals() {
+        assert_eq!(
+            Parser::new("TRUE").parse_expression(),
+            Ok(Expression::Value(Value::Bool(true)))
+        );
+        assert_eq!(
+            Parser::new("false").parse_expression(),
+            Ok(Expression::Value(Value::Bool(false)))
+        );
+    }
+
+    #[test]
+    fn parse_bool_comparison() {
+        let sql = "DELETE FROM users WHERE active = TRUE;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Delete {
+                from: "users".into(),
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("active".into())),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Bool(true))),
+                }),
+                returning: vec![],
+            })
+        )
+    }
+
+    #[test]
+    fn parse_alter_table_add_column() {
+        let sql = "ALTER TABLE users ADD COLUMN age INT;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::AlterTable {
+                table: "users".into(),
+                action: AlterAction::AddColumn(Column::new("age", DataType::Int)),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_alter_table_add_column_without_column_keyword() {
+        let sql = "ALTER TABLE users ADD email VARCHAR(255) UNIQUE;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::AlterTable {
+                table: "users".into(),
+                action: AlterAction::AddColumn(Column::unique("email", DataType::Varchar(255))),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_alter_table_drop_column() {
+        for sql in [
+            "ALTER TABLE users DROP COLUMN age;",
+            "ALTER TABLE users DROP age;",
+        ] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Ok(Statement::AlterTable {
+                    table: "users".into(),
+                    action: AlterAction::DropColumn("age".into()),
+                })
+            )
+        }
+    }
+
+    #[test]
+    fn parse_alter_table_rename_column() {
+        let sql = "ALTER TABLE users RENAME COLUMN name TO username;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::AlterTable {
+                table: "users".into(),
+                action: AlterAction::RenameColumn {
+                    from: "name".into(),
+                    to: "username".into(),
+                },
+            })
+        )
+    }
+
+    #[test]
+    fn parse_alter_table_rename_table() {
+        let sql = "ALTER TABLE users RENAME TO customers;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::AlterTable {
+                table: "users".into(),
+                action: AlterAction::RenameTable("customers".into()),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_drop_database() {
+        let sql = "DROP DATABASE test;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Drop(Drop::Database {
+                name: "test".into(),
+                if_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_multi_column_unique_index() {
+        let sql = "CREATE UNIQUE INDEX name_uq_idx ON users(first_name, last_name);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Index {
+                name: "name_uq_idx".into(),
+                table: "users".into(),
+                columns: vec!["first_name".into(), "last_name".into()],
+                unique: true,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_drop_index() {
+        let sql = "DROP INDEX email_uq_idx;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Drop(Drop::Index("email_uq_idx".into())))
+        )
+    }
+
+    #[test]
+    fn parse_drop_database_if_exists() {
+        let sql = "DROP DATABASE IF EXISTS test;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Drop(Drop::Database {
+                name: "test".into(),
+                if_exists: true,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_truncate() {
+        assert_eq!(
+            Parser::new("TRUNCATE users;").parse_statement(),
+            Ok(Statement::Truncate("users".into()))
+        );
+    }
+
+    #[test]
+    fn parse_truncate_table() {
+        assert_eq!(
+            Parser::new("TRUNCATE TABLE users;").parse_statement(),
+            Ok(Statement::Truncate("users".into()))
+        );
+    }
+
+    #[test]
+    fn parse_start_transaction() {
+        assert_eq!(
+            Parser::new("START TRANSACTION;").parse_statement(),
+            Ok(Statement::StartTransaction)
+        );
+    }
+
+    #[test]
+    fn parse_begin() {
+        assert_eq!(
+            Parser::new("BEGIN;").parse_statement(),
+            Ok(Statement::StartTransaction)
+        );
+    }
+
+    #[test]
+    fn parse_commit() {
+        assert_eq!(
+            Parser::new("COMMIT;").parse_statement(),
+            Ok(Statement::Commit)
+        );
+    }
+
+    #[test]
+    fn parse_rollback() {
+        assert_eq!(
+            Parser::new("ROLLBACK;").parse_statement(),
+            Ok(Statement::Rollback)
+        );
+    }
+
+    #[test]
+    fn parse_savepoint() {
+        assert_eq!(
+            Parser::new("SAVEPOINT before_update;").parse_statement(),
+            Ok(Statement::Savepoint("before_update".into()))
+        );
+    }
+
+    #[test]
+    fn parse_rollback_to_savepoint() {
+        for sql in [
+            "ROLLBACK TO SAVEPOINT before_update;",
+            "ROLLBACK TO before_update;",
+        ] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Ok(Statement::RollbackTo("before_update".into()))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_quoted_identifiers() {
+        let sql = "SELECT `order`, `select` FROM `table` WHERE `order` > 1;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![
+                    Expression::Identifier("order".into()).into(),
+                    Expression::Identifier("select".into()).into(),
+                ],
+                from: Some("table".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("order".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None,
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_create_table_with_quoted_identifiers() {
+        let sql = "CREATE TABLE orders (id INT PRIMARY KEY, `order` INT);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "orders".into(),
+                columns: vec![
+                    Column::primary_key("id", DataType::Int),
+                    Column::new("order", DataType::Int),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let statements = [
+            "CREATE DATABASE IF NOT EXISTS test;",
+            "CREATE TABLE IF NOT EXISTS users (id INT UNSIGNED AUTO_INCREMENT, name VARCHAR(255) NOT NULL DEFAULT 'none', price DECIMAL(10,2) CHECK (price > 0), owner INT REFERENCES owners(id), PRIMARY KEY (id));",
+            "CREATE UNIQUE INDEX email_idx ON users(email);",
+            "CREATE INDEX name_age_idx ON users(name, age);",
+            "CREATE TABLE products (id INT PRIMARY KEY, price INT CHECK (price >= 0), discount INT, CHECK (discount <= price));",
+            "DROP TABLE IF EXISTS users;",
+            "DROP DATABASE test;",
+            "DROP INDEX email_idx;",
+            "CREATE VIEW adults AS SELECT id, name FROM users WHERE age >= 18;",
+            "DROP VIEW adults;",
+            "SHOW TABLES;",
+            "SHOW COLUMNS FROM users;",
+            "DESCRIBE users;",
+            "USE test;",
+            "SELECT * FROM t WHERE (a, b) = (1, 2) OR (a, b + 1) IN ((3, 4), (5, 6)) OR (a) = 7;",
+            "SELECT flags & 4, (a | b) << 2, a ^ b >> 1 FROM t WHERE flags & mask = mask;",
+            "SELECT * FROM users WHERE name ILIKE 'john%' AND email LIKE '%@test.com';",
+            r"SELECT * FROM files WHERE name LIKE '%\_%' ESCAPE '\' OR name LIKE 'a%';",
+            "SELECT * FROM products WHERE price > ALL (SELECT price FROM offers) AND id = ANY (SELECT id FROM stock);",
+            "WITH a AS (SELECT id FROM users), b AS (SELECT id FROM a) SELECT * FROM b;",
+            "CREATE VIEW v AS WITH a AS (SELECT id FROM users) SELECT * FROM a;",
+            "INSERT INTO archive (id) WITH old AS (SELECT id FROM users) SELECT id FROM old;",
+            "SELECT * FROM (WITH a AS (SELECT id FROM users) SELECT id FROM a) AS t WHERE EXISTS (WITH b AS (SELECT 1) SELECT * FROM b);",
+            "WITH a AS (WITH b AS (SELECT id FROM users) SELECT id FROM b) SELECT * FROM a;",
+            "SELECT p.id FROM shop.products AS p;",
+            "SELECT * FROM users ORDER BY age DESC NULLS LAST, name NULLS FIRST, id;",
+            "SELECT COUNT(DISTINCT customer_id), COUNT(*) FROM orders;",
+            "TRUNCATE TABLE users;",
+            "ALTER TABLE users ADD COLUMN age INT DEFAULT 18;",
+            "ALTER TABLE users DROP COLUMN age;",
+            "ALTER TABLE users RENAME COLUMN name TO username;",
+            "ALTER TABLE users RENAME TO customers;",
+            "SELECT DISTINCT u.id AS user, o.* FROM users AS u INNER JOIN orders o ON u.id = o.user_id LEFT JOIN items ON items.id = o.item WHERE u.id IN (1, 2) AND NOT u.name LIKE 'J%' GROUP BY u.id HAVING COUNT(*) > 1 ORDER BY u.id LIMIT 10 OFFSET 5;",
+            "SELECT * FROM (SELECT id FROM users) AS t WHERE EXISTS (SELECT id FROM orders) AND id NOT IN (SELECT id FROM banned) AND (SELECT 1 FROM t) = 1;",
+            "SELECT id FROM a UNION ALL SELECT id FROM b EXCEPT SELECT id FROM c;",
+            "SELECT * FROM (SELECT id FROM a UNION SELECT id FROM b) AS t WHERE id IN (SELECT id FROM c INTERSECT SELECT id FROM d);",
+            "SELECT CASE WHEN x BETWEEN 1 AND 2 THEN 'a' ELSE CAST(x AS VARCHAR(10)) END, -x % 2, x IS NOT NULL FROM t;",
+            "INSERT INTO users (id, name) VALUES (1, 'O''Brien'), (2, \"Jane\") RETURNING *;",
+            "INSERT INTO users SELECT * FROM customers;",
+            "UPDATE users SET name = ?, age = :age WHERE id = 1 RETURNING id;",
+            "DELETE FROM users WHERE id >= 10 RETURNING id, name;",
+            "START TRANSACTION;",
+            "SAVEPOINT sp;",
+            "ROLLBACK TO SAVEPOINT sp;",
+            "ROLLBACK;",
+            "COMMIT;",
+            "EXPLAIN SELECT * FROM users;",
+        ];
+
+        for sql in statements {
+            let statement = Parser::new(sql).parse_statement().unwrap();
+            let displayed = statement.to_string();
+
+            assert_eq!(
+                Parser::new(&displayed).parse_statement(),
+                Ok(statement),
+                "{sql} was displayed as {displayed}"
+            );
+        }
+    }
+
+    #[test]
+    fn display_data_types() {
+        let data_types = [
+            (DataType::SmallInt, "SMALLINT"),
+            (DataType::UnsignedSmallInt, "SMALLINT UNSIGNED"),
+            (DataType::Int, "INT"),
+            (DataType::UnsignedInt, "INT UNSIGNED"),
+            (DataType::BigInt, "BIGINT"),
+            (DataType::UnsignedBigInt, "BIGINT UNSIGNED"),
+            (DataType::Bool, "BOOL"),
+            (DataType::Float, "FLOAT"),
+            (
+                DataType::Decimal {
+                    precision: 10,
+                    scale: 2,
+                },
+                "DECIMAL(10,2)",
+            ),
+            (DataType::Date, "DATE"),
+            (DataType::Timestamp, "TIMESTAMP"),
+            (DataType::Varchar(255), "VARCHAR(255)"),
+            (DataType::Text, "TEXT"),
+        ];
+
+        for (data_type, expected) in data_types {
+            assert_eq!(data_type.to_string(), expected);
+            assert_eq!(Parser::new(expected).parse_data_type(), Ok(data_type));
+        }
+    }
+
+    #[test]
+    fn display_values() {
+        let values = [
+            (Value::Number(-5), "-5"),
+            (Value::Float(1.5), "1.5"),
+            (Value::Bool(true), "TRUE"),
+            (Value::Bool(false), "FALSE"),
+            (Value::Null, "NULL"),
+            (Value::String("mkdb".into()), "\"mkdb\""),
+            (Value::String("say \"hi\"".into()), "\"say \"\"hi\"\"\""),
+        ];
+
+        for (value, expected) in values {
+            assert_eq!(value.to_string(), expected);
+        }
+
+        let quoted = Value::String("it's \"quoted\"".into());
+
+        assert_eq!(
+            Parser::new(&quoted.to_string()).parse_expression(),
+            Ok(Expression::Value(quoted))
+        );
+    }
+
+    #[test]
+    fn display_expressions_with_minimal_parenthesis() {
+        let ident = |name: &str| Box::new(Expression::Identifier(name.into()));
+
+        let binary = |left, operator, right| {
+            Box::new(Expression::BinaryOperation {
+                left,
+                operator,
+                right,
+            })
+        };
+
+        let unary = |operator, expr| Box::new(Expression::UnaryOperation { operator, expr });
+
+        let a_plus_b = || binary(ident("a"), BinaryOperator::Plus, ident("b"));
+
+        let cases = [
+            (
+                binary(a_plus_b(), BinaryOperator::Mul, ident("c")),
+                "(a + b) * c",
+            ),
+            (
+                binary(a_plus_b(), BinaryOperator::Plus, ident("c")),
+                "a + b + c",
+            ),
+            (
+                binary(ident("c"), BinaryOperator::Mul, a_plus_b()),
+                "c * (a + b)",
+            ),
+            (
+                binary(
+                    ident("a"),
+                    BinaryOperator::Minus,
+                    binary(ident("b"), BinaryOperator::Minus, ident("c")),
+                ),
+                "a - (b - c)",
+            ),
+            (
+                binary(
+                    ident("a"),
+                    BinaryOperator::Plus,
+                    binary(ident("b"), BinaryOperator::Mod, ident("c")),
+                ),
+                "a + b % c",
+            ),
+            (
+                binary(
+                    binary(ident("a"), BinaryOperator::Or, ident("b")),
+                    BinaryOperator::And,
+                    ident("c"),
+                ),
+                "(a OR b) AND c",
+            ),
+            (
+                binary(
+                    binary(ident("a"), BinaryOperator::And, ident("b")),
+                    BinaryOperator::Or,
+                    ident("c"),
+                ),
+                "a AND b OR c",
+            ),
+            (
+                unary(
+                    UnaryOperator::Not,
+                    binary(ident("a"), BinaryOperator::And, ident("b")),
+                ),
+                "NOT (a AND b)",
+            ),
+            (
+                unary(
+                    UnaryOperator::Not,
+                    binary(ident("a"), BinaryOperator::Eq, ident("b")),
+                ),
+                "NOT a = b",
+            ),
+            (unary(UnaryOperator::Minus, a_plus_b()), "-(a + b)"),
+            (
+                unary(
+                    UnaryOperator::Minus,
+                    unary(UnaryOperator::Minus, ident("a")),
+                ),
+                "-(-a)",
+            ),
+            (
+                Box::new(Expression::Between {
+                    expr: ident("a"),
+                    low: a_plus_b(),
+                    high: binary(ident("a"), BinaryOperator::And, ident("b")),
+                    negated: false,
+                }),
+                "a BETWEEN a + b AND (a AND b)",
+            ),
+        ];
+
+        for (expr, expected) in cases {
+            assert_eq!(expr.to_string(), expected);
+            assert_eq!(
+                Parser::new(expected)
+                    .parse_expression()
+                    .unwrap()
+                    .to_string(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn display_quotes_identifiers_that_are_keywords() {
+        let sql = "CREATE TABLE orders (id INT PRIMARY KEY, `order` INT);";
+        let statement = Parser::new(sql).parse_statement().unwrap();
+
+        assert_eq!(statement.to_string(), sql);
+    }
+
+    #[test]
+    fn parse_non_reserved_keywords_as_identifiers() {
+        let sql =
+            "CREATE TABLE key (key INT PRIMARY KEY, value VARCHAR(255), name TEXT, date DATE);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::Table {
+                name: "key".into(),
+                columns: vec![
+                    Column::primary_key("key", DataType::Int),
+                    Column::new("value", DataType::Varchar(255)),
+                    Column::new("name", DataType::Text),
+                    Column::new("date", DataType::Date),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
+        );
+
+        let sql = "SELECT key, value, name FROM key WHERE key.date = 1;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![
+                    Expression::Identifier("key".into()).into(),
+                    Expression::Identifier("value".into()).into(),
+                    Expression::Identifier("name".into()).into(),
+                ],
+                from: Some("key".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::QualifiedIdentifier {
+                        table: "key".into(),
+                        column: "date".into(),
+                    }),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        );
+    }
+
+    #[test]
+    fn reserved_keywords_are_not_identifiers() {
+        let sql = "CREATE TABLE users (select INT);";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Identifier(Default::default()),
+                    found: Token::Keyword(Keyword::Select),
+                },
+                location: Location { line: 1, col: 21 },
+                end: Location { line: 1, col: 27 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_drop_table() {
+        let sql = "DROP TABLE test;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Drop(Drop::Table {
+                name: "test".into(),
+                if_exists: false,
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_view() {
+        let sql = "CREATE VIEW adults AS SELECT id, name FROM users WHERE age >= 18;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Create(Create::View {
+                name: "adults".into(),
+                query: Box::new(Statement::Select(Box::new(Select {
+                    ctes: vec![],
+                    distinct: false,
+                    columns: vec![
+                        Expression::Identifier("id".into()).into(),
+                        Expression::Identifier("name".into()).into(),
+                    ],
+                    from: Some("users".into()),
+                    joins: vec![],
+                    r#where: Some(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("age".into())),
+                        operator: BinaryOperator::GtEq,
+                        right: Box::new(Expression::Value(Value::Number(18))),
+                    }),
+                    group_by: vec![],
+                    having: None,
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                }))),
+            }))
+        )
+    }
+
+    #[test]
+    fn parse_create_view_with_non_select_query() {
+        let sql = "CREATE VIEW v AS DELETE FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::ExpectedOneOf {
+                    expected: vec![
+                        Token::Keyword(Keyword::Select),
+                        Token::Keyword(Keyword::With)
+                    ],
+                    found: Token::Keyword(Keyword::Delete),
+                },
+                location: Location { line: 1, col: 18 },
+                end: Location { line: 1, col: 24 },
+                input: sql.into(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_drop_view() {
+        let sql = "DROP VIEW adults;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Drop(Drop::View("adults".into())))
+        )
+    }
+
+    #[test]
+    fn parse_show_tables() {
+        assert_eq!(
+            Parser::new("SHOW TABLES;").parse_statement(),
+            Ok(Statement::ShowTables)
+        )
+    }
+
+    #[test]
+    fn parse_show_columns() {
+        assert_eq!(
+            Parser::new("SHOW COLUMNS FROM users;").parse_statement(),
+            Ok(Statement::ShowColumns {
+                table: "users".into()
+            })
+        )
+    }
+
+    #[test]
+    fn parse_show_columns_without_table() {
+        let sql = "SHOW COLUMNS FROM;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Identifier(Default::default()),
+                    found: Token::SemiColon,
+                },
+                location: Location { line: 1, col: 18 },
+                end: Location { line: 1, col: 19 },
+                input: sql.into(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_describe() {
+        for sql in ["DESCRIBE users;", "DESC users;", "describe users;"] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Ok(Statement::Describe("users".into()))
+            )
+        }
+    }
+
+    #[test]
+    fn parse_describe_without_table() {
+        let sql = "DESC;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Identifier(Default::default()),
+                    found: Token::SemiColon,
+                },
+                location: Location { line: 1, col: 5 },
+                end: Location { line: 1, col: 6 },
+                input: sql.into(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_use_database() {
+        assert_eq!(
+            Parser::new("USE test;").parse_statement(),
+            Ok(Statement::Use("test".into()))
+        )
+    }
+
+    #[test]
+    fn parse_use_without_database() {
+        let sql = "USE;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Identifier(Default::default()),
+                    found: Token::SemiColon,
+                },
+                location: Location { line: 1, col: 4 },
+                end: Location { line: 1, col: 5 },
+                input: sql.into(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_database_qualified_table() {
+        for (sql, database, alias) in [
+            ("SELECT * FROM shop.products;", Some("shop"), None),
+            ("SELECT * FROM shop.products p;", Some("shop"), Some("p")),
+            ("SELECT * FROM products;", None, None),
+        ] {
+            let Ok(Statement::Select(select)) = Parser::new(sql).parse_statement() else {
+                panic!("failed to parse {sql}");
+            };
+            let Select { from, .. } = *select;
+
+            assert_eq!(
+                from,
+                Some(TableReference::Table {
+                    database: database.map(String::from),
+                    name: "products".into(),
+                    alias: alias.map(String::from),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parse_database_qualified_table_without_name() {
+        let sql = "SELECT * FROM shop.;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Identifier(Default::default()),
+                    found: Token::SemiColon,
+                },
+                location: Location { line: 1, col: 20 },
+                end: Location { line: 1, col: 21 },
+                input: sql.into(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_drop_table_if_exists() {
+        let sql = "DROP TABLE IF EXISTS test;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Drop(Drop::Table {
+                name: "test".into(),
+                if_exists: true,
+            }))
+        )
+    }
+
+    #[test]
+    fn iterate_statements() {
+        let sql = r#"
+            DROP TABLE test;
+            COMMIT;
+            SELECT * FROM products;
+        "#;
+
+        assert_eq!(
+            Parser::new(sql)
+                .statements()
+                .collect::<ParseResult<Vec<_>>>(),
+            Parser::new(sql).try_parse()
+        );
+        assert_eq!(Parser::new(sql).statements().count(), 3);
+    }
+
+    #[test]
+    fn stop_iterating_statements_early() {
+        let sql = "COMMIT; ROLLBACK; SELECT FROM;";
+
+        let mut parsed = Vec::new();
+
+        for statement in Parser::new(sql).statements() {
+            // Panics if the malformed statement is ever parsed.
+            let statement = statement.unwrap();
+            let stop = statement == Statement::Rollback;
+            parsed.push(statement);
+
+            if stop {
+                break;
+            }
+        }
+
+        assert_eq!(parsed, vec![Statement::Commit, Statement::Rollback]);
+    }
+
+    #[test]
+    fn stop_iterating_statements_after_error() {
+        let mut statements = Parser::new("COMMIT; SELECT FROM; ROLLBACK;").statements();
+
+        assert_eq!(statements.next(), Some(Ok(Statement::Commit)));
+        assert!(matches!(statements.next(), Some(Err(_))));
+        assert_eq!(statements.next(), None);
+    }
+
+    #[test]
+    fn try_parse_all_recovers_from_errors() {
+        let sql = r#"
+            DELETE users WHERE id = 1;
+            DROP TABLE test;
+            SELECT * FROM products;
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).try_parse_all(),
+            Err(vec![ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Keyword(Keyword::From),
+                    found: Token::Identifier("users".into()),
+                },
+                location: Location { line: 2, col: 20 },
+                end: Location { line: 2, col: 25 },
+                input: sql.to_owned(),
+            }])
+        );
+    }
+
+    #[test]
+    fn try_parse_all_collects_multiple_errors() {
+        let sql = r#"
+            UPDATE users is_admin = 1;
+            DROP TABLE test;
+            DELETE users WHERE id = 1;
+            SELECT * FROM;
+            SELECT * FROM products;
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).try_parse_all(),
+            Err(vec![
+                ParserError {
+                    kind: ErrorKind::Expected {
+                        expected: Token::Keyword(Keyword::Set),
+                        found: Token::Identifier("is_admin".into()),
+                    },
+                    location: Location { line: 2, col: 26 },
+                    end: Location { line: 2, col: 34 },
+                    input: sql.to_owned(),
+                },
+                ParserError {
+                    kind: ErrorKind::Expected {
+                        expected: Token::Keyword(Keyword::From),
+                        found: Token::Identifier("users".into()),
+                    },
+                    location: Location { line: 4, col: 20 },
+                    end: Location { line: 4, col: 25 },
+                    input: sql.to_owned(),
+                },
+                ParserError {
+                    kind: ErrorKind::Expected {
+                        expected: Token::Identifier(Default::default()),
+                        found: Token::SemiColon,
+                    },
+                    location: Location { line: 5, col: 26 },
+                    end: Location { line: 5, col: 27 },
+                    input: sql.to_owned(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn try_parse_all_without_errors() {
+        let sql = "DROP TABLE test; SELECT * FROM products;";
+
+        assert_eq!(
+            Parser::new(sql).try_parse_all(),
+            Parser::new(sql).try_parse().map_err(|err| vec![err])
+        );
+    }
+
+    #[test]
+    fn parse_multiple_statements() {
+        let sql = r#"
+            DROP TABLE test;
+            UPDATE users SET is_admin = 1;
+            SELECT * FROM products;
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).try_parse(),
+            Ok(vec![
+                Statement::Drop(Drop::Table {
+                    name: "test".into(),
+                    if_exists: false,
+                }),
+                Statement::Update {
+                    table: "users".into(),
+                    columns: vec![Assignment {
+                        identifier: "is_admin".into(),
+                        value: Expression::Value(Value::Number(1)),
+                    }],
+                    r#where: None,
+                    returning: vec![],
+                },
+                Statement::Select(Box::new(Select {
+                    ctes: vec![],
+                    distinct: false,
+                    columns: vec![Expression::Wildcard.into()],
+                    from: Some("products".into()),
+                    joins: vec![],
+                    r#where: None,
+                    group_by: vec![],
+                    having: None,
+                    order_by: vec![],
+                    limit: None,
+                    offset: None,
+                }))
+            ])
+        )
+    }
+
+    #[test]
+    fn skip_empty_statements() {
+        assert_eq!(Parser::new(";;").try_parse(), Ok(vec![]));
+        assert_eq!(Parser::new(" \n\t ").try_parse(), Ok(vec![]));
+        assert_eq!(Parser::new("; \n ;\t;").try_parse(), Ok(vec![]));
+        assert_eq!(
+            Parser::new(";COMMIT;; ;ROLLBACK;;").try_parse(),
+            Ok(vec![Statement::Commit, Statement::Rollback])
+        );
+        assert_eq!(
+            Parser::new(";;COMMIT;;")
+                .statements()
+                .collect::<ParseResult<Vec<_>>>(),
+            Ok(vec![Statement::Commit])
+        );
+        assert_eq!(
+            Parser::new("COMMIT;;").try_parse_all(),
+            Ok(vec![Statement::Commit])
+        );
+    }
+
+    #[test]
+    fn parse_statements_with_comments() {
+        let sql = r#"
+            -- Start from scratch.
+            DROP TABLE test; -- Gone.
+            -- Admins only.
+            DELETE FROM users -- Everyone
+            WHERE is_admin = 0;
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).try_parse(),
+            Ok(vec![
+                Statement::Drop(Drop::Table {
+                    name: "test".into(),
+                    if_exists: false,
+                }),
+                Statement::Delete {
+                    from: "users".into(),
+                    r#where: Some(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("is_admin".into())),
+                        operator: BinaryOperator::Eq,
+                        right: Box::new(Expression::Value(Value::Number(0))),
+                    }),
+                    returning: vec![],
+                },
+            ])
+        )
+    }
+
+    #[test]
+    fn parse_statement_with_multi_line_comments() {
+        let sql = r#"
+            /*
+             * Remove products that
+             * nobody can afford.
+             */
+            DELETE FROM /* all */ products WHERE price > /* way too much */ 5000;
+        "#;
+
+        assert_eq!(
+            Parser::new(sql).try_parse(),
+            Ok(vec![Statement::Delete {
+                from: "products".into(),
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(5000))),
+                }),
+                returning: vec![],
+            }])
+        )
+    }
+
+    #[test]
+    fn parse_explain() {
+        let sql = "EXPLAIN SELECT name, email FROM users ORDER BY email;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Explain(Box::new(Statement::Select(Box::new(
+                Select {
+                    ctes: vec![],
+                    distinct: false,
+                    columns: vec![
+                        Expression::Identifier("name".into()).into(),
+                        Expression::Identifier("email".into()).into()
+                    ],
+                    from: Some("users".into()),
+                    joins: vec![],
+                    r#where: None,
+                    group_by: vec![],
+                    having: None,
+                    order_by: vec![Expression::Identifier("email".into()).into()],
+                    limit: None,
+                    offset: None
+                }
+            )))))
+        )
+    }
+
+    #[test]
+    fn parse_explain_insert() {
+        let sql = "EXPLAIN INSERT INTO users (id, name) VALUES (1, 'John');";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Explain(Box::new(Statement::Insert {
+                into: "users".into(),
+                columns: ["id", "name"].map(String::from).into(),
+                source: InsertSource::Values(vec![vec![
+                    Expression::Value(Value::Number(1)),
+                    Expression::Value(Value::String("John".into())),
+                ]]),
+                returning: vec![],
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_explain_requires_single_terminator() {
+        let sql = "EXPLAIN SELECT * FROM users;;";
+        let mut parser = Parser::new(sql);
+
+        assert!(matches!(
+            parser.parse_statement(),
+            Ok(Statement::Explain(_))
+        ));
+        assert!(parser.parse_statement().is_err());
+    }
+
+    #[test]
+    fn parse_parameters() {
+        let sql = "UPDATE users SET name = ? WHERE id = ? AND age > ?;";
+        let mut parser = Parser::new(sql);
+
+        assert_eq!(
+            parser.parse_statement(),
+            Ok(Statement::Update {
+                table: "users".into(),
+                columns: vec![Assignment {
+                    identifier: "name".into(),
+                    value: Expression::Parameter(1),
+                }],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("id".into())),
+                        operator: BinaryOperator::Eq,
+                        right: Box::new(Expression::Parameter(2)),
+                    }),
+                    operator: BinaryOperator::And,
+                    right: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("age".into())),
+                        operator: BinaryOperator::Gt,
+                        right: Box::new(Expression::Parameter(3)),
+                    }),
+                }),
+                returning: vec![],
+            })
+        );
+
+        assert_eq!(parser.parameter_count(), 3);
+    }
+
+    #[test]
+    fn parameters_are_numbered_per_statement() {
+        let sql = "DELETE FROM users WHERE id = ?; INSERT INTO users (id, name) VALUES (?, ?);";
+        let mut parser = Parser::new(sql);
+
+        parser.parse_statement().unwrap();
+        assert_eq!(parser.parameter_count(), 1);
+
+        assert_eq!(
+            parser.parse_statement(),
+            Ok(Statement::Insert {
+                into: "users".into(),
+                columns: ["id", "name"].map(String::from).into(),
+                source: InsertSource::Values(vec![vec![
+                    Expression::Parameter(1),
+                    Expression::Parameter(2),
+                ]]),
+                returning: vec![],
+            })
+        );
+        assert_eq!(parser.parameter_count(), 2);
+    }
+
+    #[test]
+    fn parse_named_parameters() {
+        let sql = "DELETE FROM users WHERE id = :id OR email = @email;";
+        let mut parser = Parser::new(sql);
+
+        assert_eq!(
+            parser.parse_statement(),
+            Ok(Statement::Delete {
+                from: "users".into(),
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("id".into())),
+                        operator: BinaryOperator::Eq,
+                        right: Box::new(Expression::NamedParameter("id".into())),
+                    }),
+                    operator: BinaryOperator::Or,
+                    right: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("email".into())),
+                        operator: BinaryOperator::Eq,
+                        right: Box::new(Expression::NamedParameter("email".into())),
+                    }),
+                }),
+                returning: vec![],
+            })
+        );
+
+        assert_eq!(parser.parameter_count(), 2);
+    }
+
+    #[test]
+    fn parse_repeated_named_parameters() {
+        let mut parser = Parser::new("SELECT * FROM users WHERE :x = :x OR id = @x;");
+
+        assert!(parser.parse_statement().is_ok());
+        assert_eq!(parser.parameter_count(), 1);
+    }
+
+    #[test]
+    fn arithmetic_operator_precedence() {
+        let expr = "price * discount / 100 < 10 + 20 * 30";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("price".into())),
+                        operator: BinaryOperator::Mul,
+                        right: Box::new(Expression::Identifier("discount".into())),
+                    }),
+                    operator: BinaryOperator::Div,
+                    right: Box::new(Expression::Value(Value::Number(100))),
+                }),
+                operator: BinaryOperator::Lt,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Value(Value::Number(10))),
+                    operator: BinaryOperator::Plus,
+                    right: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Value(Value::Number(20))),
+                        operator: BinaryOperator::Mul,
+                        right: Box::new(Expression::Value(Value::Number(30))),
+                    })
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn nested_arithmetic_precedence() {
+        let expr = "price * discount >= 10 - (20 + 50) / (2 * (4 + (1 - 1)))";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Mul,
+                    right: Box::new(Expression::Identifier("discount".into())),
+                }),
+                operator: BinaryOperator::GtEq,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Value(Value::Number(10))),
+                    operator: BinaryOperator::Minus,
+                    right: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Nested(Box::new(Expression::BinaryOperation {
+                            left: Box::new(Expression::Value(Value::Number(20))),
+                            operator: BinaryOperator::Plus,
+                            right: Box::new(Expression::Value(Value::Number(50))),
+                        }))),
+                        operator: BinaryOperator::Div,
+                        right: Box::new(Expression::Nested(Box::new(
+                            Expression::BinaryOperation {
+                                left: Box::new(Expression::Value(Value::Number(2))),
+                                operator: BinaryOperator::Mul,
+                                right: Box::new(Expression::Nested(Box::new(
+                                    Expression::BinaryOperation {
+                                        left: Box::new(Expression::Value(Value::Number(4))),
+                                        operator: BinaryOperator::Plus,
+                                        right: Box::new(Expression::Nested(Box::new(
+                                            Expression::BinaryOperation {
+                                                left: Box::new(Expression::Value(Value::Number(1))),
+                                                operator: BinaryOperator::Minus,
+                                                right: Box::new(Expression::Value(Value::Number(
+                                                    1
+                                                ))),
+                                            }
+                                        )))
+                                    }
+                                )))
+                            }
+                        )))
+                    })
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn and_or_operators_precedence() {
+        let expr = "100 <= price AND price <= 200 OR price > 1000";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Value(Value::Number(100))),
+                        operator: BinaryOperator::LtEq,
+                        right: Box::new(Expression::Identifier("price".into())),
+                    }),
+                    operator: BinaryOperator::And,
+                    right: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("price".into())),
+                        operator: BinaryOperator::LtEq,
+                        right: Box::new(Expression::Value(Value::Number(200))),
+                    }),
+                }),
+                operator: BinaryOperator::Or,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(1000))),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn like_operator_precedence() {
+        let expr = r#"name LIKE "A%" AND age > 18"#;
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Like {
+                    expr: Box::new(Expression::Identifier("name".into())),
+                    pattern: Box::new(Expression::Value(Value::String("A%".into()))),
+                    escape: None,
+                    case_insensitive: false,
+                }),
+                operator: BinaryOperator::And,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("age".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(18))),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn parse_like_with_and_without_escape() {
+        for (expr, escape) in [
+            (r"name LIKE '100\%'", None),
+            (r"name LIKE '100\%' ESCAPE '\'", Some('\\')),
+            ("name LIKE '100!%' ESCAPE '!'", Some('!')),
+        ] {
+            let pattern = expr.split('\'').nth(1).unwrap();
+
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::Like {
+                    expr: Box::new(Expression::Identifier("name".into())),
+                    pattern: Box::new(Expression::Value(Value::String(pattern.into()))),
+                    escape,
+                    case_insensitive: false,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parse_like_and_ilike() {
+        for (expr, case_insensitive) in [
+            ("name LIKE 'john%'", false),
+            ("name ILIKE 'john%'", true),
+            ("name ilike 'john%'", true),
+        ] {
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::Like {
+                    expr: Box::new(Expression::Identifier("name".into())),
+                    pattern: Box::new(Expression::Value(Value::String("john%".into()))),
+                    escape: None,
+                    case_insensitive,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn ilike_operator_precedence() {
+        let expr = "name ILIKE 'J%' OR NOT email ILIKE '%@TEST.COM' ESCAPE '!'";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Like {
+                    expr: Box::new(Expression::Identifier("name".into())),
+                    pattern: Box::new(Expression::Value(Value::String("J%".into()))),
+                    escape: None,
+                    case_insensitive: true,
+                }),
+                operator: BinaryOperator::Or,
+                right: Box::new(Expression::UnaryOperation {
+                    operator: UnaryOperator::Not,
+                    expr: Box::new(Expression::Like {
+                        expr: Box::new(Expression::Identifier("email".into())),
+                        pattern: Box::new(Expression::Value(Value::String("%@TEST.COM".into()))),
+                        escape: Some('!'),
+                        case_insensitive: true,
+                    }),
+                }),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_like_escape_precedence() {
+        let expr = "name LIKE '%!_%' ESCAPE '!' AND age > 18";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Like {
+                    expr: Box::new(Expression::Identifier("name".into())),
+                    pattern: Box::new(Expression::Value(Value::String("%!_%".into()))),
+                    escape: Some('!'),
+                    case_insensitive: false,
+                }),
+                operator: BinaryOperator::And,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("age".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(18))),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn parse_escape_as_identifier() {
+        let sql = "SELECT escape FROM t WHERE escape LIKE 'a!%' ESCAPE '!';";
+
+        let Ok(Statement::Select(select)) = Parser::new(sql).parse_statement() else {
+            panic!("failed to parse {sql}");
+        };
+        let Select {
+            columns, r#where, ..
+        } = *select;
+
+        assert_eq!(
+            columns,
+            vec![Expression::Identifier("escape".into()).into()]
+        );
+        assert_eq!(
+            r#where,
+            Some(Expression::Like {
+                expr: Box::new(Expression::Identifier("escape".into())),
+                pattern: Box::new(Expression::Value(Value::String("a!%".into()))),
+                escape: Some('!'),
+                case_insensitive: false,
+            })
+        );
+
+        assert!(
+            Parser::new("CREATE TABLE t (id INT PRIMARY KEY, escape VARCHAR(1));")
+                .parse_statement()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn parse_like_escape_not_single_character() {
+        let expr = "name LIKE 'a' ESCAPE 'ab'";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Err(ParserError {
+                kind: ErrorKind::Other("ESCAPE must be a single character, found 'ab'".into()),
+                location: Location { line: 1, col: 22 },
+                end: Location { line: 1, col: 26 },
+                input: expr.into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_like_escape_not_string() {
+        let expr = "name LIKE 'a' ESCAPE 1";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::String(Default::default()),
+                    found: Token::Number("1".into()),
+                },
+                location: Location { line: 1, col: 22 },
+                end: Location { line: 1, col: 23 },
+                input: expr.into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_row_equality() {
+        let ident = |name: &str| Expression::Identifier(name.into());
+        let number = |num| Expression::Value(Value::Number(num));
+
+        assert_eq!(
+            Parser::new("(a, b, c + 1) = (1, 2, 3)").parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Row(vec![
+                    ident("a"),
+                    ident("b"),
+                    Expression::BinaryOperation {
+                        left: Box::new(ident("c")),
+                        operator: BinaryOperator::Plus,
+                        right: Box::new(number(1)),
+                    },
+                ])),
+                operator: BinaryOperator::Eq,
+                right: Box::new(Expression::Row(vec![number(1), number(2), number(3)])),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_row_in_list() {
+        let ident = |name: &str| Expression::Identifier(name.into());
+        let number = |num| Expression::Value(Value::Number(num));
+
+        assert_eq!(
+            Parser::new("(a, b) IN ((1, 2), (3, 4))").parse_expression(),
+            Ok(Expression::InList {
+                expr: Box::new(Expression::Row(vec![ident("a"), ident("b")])),
+                list: vec![
+                    Expression::Row(vec![number(1), number(2)]),
+                    Expression::Row(vec![number(3), number(4)]),
+                ],
+                negated: false,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_single_parenthesized_expression_is_not_row() {
+        assert_eq!(
+            Parser::new("(a) = (1)").parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Nested(Box::new(Expression::Identifier(
+                    "a".into()
+                )))),
+                operator: BinaryOperator::Eq,
+                right: Box::new(Expression::Nested(Box::new(Expression::Value(
+                    Value::Number(1)
+                )))),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_in_list() {
+        let expr = "status IN (1, 2, 3) AND id > 5";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::InList {
+                    expr: Box::new(Expression::Identifier("status".into())),
+                    list: vec![
+                        Expression::Value(Value::Number(1)),
+                        Expression::Value(Value::Number(2)),
+                        Expression::Value(Value::Number(3)),
+                    ],
+                    negated: false,
+                }),
+                operator: BinaryOperator::And,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("id".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(5))),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn parse_searched_case() {
+        let expr = "CASE WHEN price > 100 THEN 'hi' ELSE 'lo' END";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::Case {
+                operand: None,
+                when_then: vec![(
+                    Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("price".into())),
+                        operator: BinaryOperator::Gt,
+                        right: Box::new(Expression::Value(Value::Number(100))),
+                    },
+                    Expression::Value(Value::String("hi".into())),
+                )],
+                else_result: Some(Box::new(Expression::Value(Value::String("lo".into())))),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_simple_case() {
+        let expr = "CASE status WHEN 1 THEN 'active' WHEN 2 THEN 'banned' END";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::Case {
+                operand: Some(Box::new(Expression::Identifier("status".into()))),
+                when_then: vec![
+                    (
+                        Expression::Value(Value::Number(1)),
+                        Expression::Value(Value::String("active".into())),
+                    ),
+                    (
+                        Expression::Value(Value::Number(2)),
+                        Expression::Value(Value::String("banned".into())),
+                    ),
+                ],
+                else_result: None,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_cast() {
+        for (expr, inner, data_type) in [
+            ("CAST(price AS INT)", "price", DataType::Int),
+            ("CAST(id AS VARCHAR(10))", "id", DataType::Varchar(10)),
+            (
+                "CAST(id AS BIGINT UNSIGNED)",
+                "id",
+                DataType::UnsignedBigInt,
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::Cast {
+                    expr: Box::new(Expression::Identifier(inner.into())),
+                    data_type,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parse_exists() {
+        for (expr, negated) in [
+            ("EXISTS (SELECT id FROM orders)", false),
+            ("NOT EXISTS (SELECT id FROM orders)", true),
+        ] {
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::Exists {
+                    subquery: Box::new(Statement::Select(Box::new(Select {
+                        ctes: vec![],
+                        distinct: false,
+                        columns: vec![Expression::Identifier("id".into()).into()],
+                        from: Some("orders".into()),
+                        joins: vec![],
+                        r#where: None,
+                        group_by: vec![],
+                        having: None,
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }))),
+                    negated,
+                })
+            )
+        }
+    }
+
+    #[test]
+    fn parse_in_subquery() {
+        let expr = "id IN (SELECT user_id FROM orders) AND id > 5";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::InSubquery {
+                    expr: Box::new(Expression::Identifier("id".into())),
+                    subquery: Box::new(Statement::Select(Box::new(Select {
+                        ctes: vec![],
+                        distinct: false,
+                        columns: vec![Expression::Identifier("user_id".into()).into()],
+                        from: Some("orders".into()),
+                        joins: vec![],
+                        r#where: None,
+                        group_by: vec![],
+                        having: None,
+                        order_by: vec![],
+                        limit: None,
+                        offset: None
+                    }))),
+                    negated: false,
+                }),
+                operator: BinaryOperator::And,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("id".into())),
+                    operator: BinaryOperator::Gt,
+                    right: Box::new(Expression::Value(Value::Number(5))),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn parse_quantified_comparisons() {
+        let subquery = Box::new(Statement::Select(Box::new(Select {
+            ctes: vec![],
+            distinct: false,
+            columns: vec![Expression::Identifier("price".into()).into()],
+            from: Some("offers".into()),
+            joins: vec![],
+            r#where: None,
+            group_by: vec![],
+            having: None,
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        })));
+
+        for (expr, operator, quantifier) in [
+            (
+                "price > ALL (SELECT price FROM offers)",
+                BinaryOperator::Gt,
+                Quantifier::All,
+            ),
+            (
+                "price = ANY (SELECT price FROM offers)",
+                BinaryOperator::Eq,
+                Quantifier::Any,
+            ),
+            (
+                "price <= any (SELECT price FROM offers)",
+                BinaryOperator::LtEq,
+                Quantifier::Any,
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::Quantified {
+                    left: Box::new(Expression::Identifier("price".into())),
+                    operator,
+                    quantifier,
+                    subquery: subquery.clone(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn parse_quantified_comparison_precedence() {
+        let Ok(Expression::BinaryOperation {
+            left,
+            operator: BinaryOperator::And,
+            ..
+        }) =
+            Parser::new("price * 2 < ALL (SELECT price FROM offers) AND active").parse_expression()
+        else {
+            panic!("AND should be the outermost operator");
+        };
+
+        assert!(matches!(
+            *left,
+            Expression::Quantified {
+                left,
+                operator: BinaryOperator::Lt,
+                quantifier: Quantifier::All,
+                ..
+            } if matches!(*left, Expression::BinaryOperation { operator: BinaryOperator::Mul, .. })
+        ));
+    }
+
+    #[test]
+    fn parse_bitwise_operators() {
+        for (expr, operator) in [
+            ("a & b", BinaryOperator::BitAnd),
+            ("a | b", BinaryOperator::BitOr),
+            ("a ^ b", BinaryOperator::BitXor),
+            ("a << b", BinaryOperator::ShiftLeft),
+            ("a >> b", BinaryOperator::ShiftRight),
+        ] {
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("a".into())),
+                    operator,
+                    right: Box::new(Expression::Identifier("b".into())),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_operators_precedence() {
+        let ident = |name: &str| Box::new(Expression::Identifier(name.into()));
+        let binary = |left, operator, right| {
+            Box::new(Expression::BinaryOperation {
+                left,
+                operator,
+                right,
+            })
+        };
+
+        // Comparison < | < ^ < & < shifts < additive.
+        assert_eq!(
+            Parser::new("a = b | c ^ d & e << f + g").parse_expression(),
+            Ok(*binary(
+                ident("a"),
+                BinaryOperator::Eq,
+                binary(
+                    ident("b"),
+                    BinaryOperator::BitOr,
+                    binary(
+                        ident("c"),
+                        BinaryOperator::BitXor,
+                        binary(
+                            ident("d"),
+                            BinaryOperator::BitAnd,
+                            binary(
+                                ident("e"),
+                                BinaryOperator::ShiftLeft,
+                                binary(ident("f"), BinaryOperator::Plus, ident("g"))
+                            )
+                        )
+                    )
+                )
+            ))
+        );
+
+        // Same precedence in reverse order.
+        assert_eq!(
+            Parser::new("a + b >> c & d ^ e | f > g").parse_expression(),
+            Ok(*binary(
+                binary(
+                    binary(
+                        binary(
+                            binary(
+                                binary(ident("a"), BinaryOperator::Plus, ident("b")),
+                                BinaryOperator::ShiftRight,
+                                ident("c")
+                            ),
+                            BinaryOperator::BitAnd,
+                            ident("d")
+                        ),
+                        BinaryOperator::BitXor,
+                        ident("e")
+                    ),
+                    BinaryOperator::BitOr,
+                    ident("f")
+                ),
+                BinaryOperator::Gt,
+                ident("g")
+            ))
+        );
+
+        // Shifts are left associative.
+        assert_eq!(
+            Parser::new("a << b >> c").parse_expression(),
+            Ok(*binary(
+                binary(ident("a"), BinaryOperator::ShiftLeft, ident("b")),
+                BinaryOperator::ShiftRight,
+                ident("c")
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_modulo() {
+        assert_eq!(
+            Parser::new("a % b").parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Identifier("a".into())),
+                operator: BinaryOperator::Mod,
+                right: Box::new(Expression::Identifier("b".into())),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_modulo_with_multiplication() {
+        assert_eq!(
+            Parser::new("a % b * c").parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("a".into())),
+                    operator: BinaryOperator::Mod,
+                    right: Box::new(Expression::Identifier("b".into())),
+                }),
+                operator: BinaryOperator::Mul,
+                right: Box::new(Expression::Identifier("c".into())),
+            })
+        );
+
+        assert_eq!(
+            Parser::new("a * b % c").parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("a".into())),
+                    operator: BinaryOperator::Mul,
+                    right: Box::new(Expression::Identifier("b".into())),
+                }),
+                operator: BinaryOperator::Mod,
+                right: Box::new(Expression::Identifier("c".into())),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_neq_alternative_spelling() {
+        assert_eq!(
+            Parser::new("a <> b").parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Identifier("a".into())),
+                operator: BinaryOperator::Neq,
+                right: Box::new(Expression::Identifier("b".into())),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_integers_and_floats() {
+        for (expr, value) in [
+            ("1", Value::Number(1)),
+            ("1.0", Value::Float(1.0)),
+            ("0.25", Value::Float(0.25)),
+            (".5", Value::Float(0.5)),
+            ("1e2", Value::Float(100.0)),
+            ("9223372036854775808", Value::Number(i64::MAX as i128 + 1)),
+            (
+                "170141183460469231731687303715884105727",
+                Value::Number(i128::MAX),
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::Value(value))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_integer_out_of_range() {
+        let sql = "170141183460469231731687303715884105728";
+
+        assert_eq!(
+            Parser::new(sql).parse_expression(),
+            Err(ParserError {
+                kind: ErrorKind::IntegerOutOfRange(sql.into()),
+                location: Location { line: 1, col: 1 },
+                end: Location { line: 1, col: 40 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_scientific_notation() {
+        for (expr, float) in [("1.5e3", 1500.0), ("2E-2", 0.02), (".5e+1", 5.0)] {
+            assert_eq!(
+                Parser::new(expr).parse_expression(),
+                Ok(Expression::Value(Value::Float(float)))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_hex_numbers() {
+        assert_eq!(
+            Parser::new("0xFF").parse_expression(),
+            Ok(Expression::Value(Value::Number(255)))
+        );
+
+        assert_eq!(
+            Parser::new("0x10 + 1").parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Value(Value::Number(16))),
+                operator: BinaryOperator::Plus,
+                right: Box::new(Expression::Value(Value::Number(1))),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_not_in_list() {
+        let expr = "name NOT IN ('Alice', 'Bob')";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::InList {
+                expr: Box::new(Expression::Identifier("name".into())),
+                list: vec![
+                    Expression::Value(Value::String("Alice".into())),
+                    Expression::Value(Value::String("Bob".into())),
+                ],
+                negated: true,
+            })
+        )
+    }
+
+    #[test]
+    fn parse_in_single_element_list() {
+        let expr = "id IN (1 + 1)";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::InList {
+                expr: Box::new(Expression::Identifier("id".into())),
+                list: vec![Expression::BinaryOperation {
+                    left: Box::new(Expression::Value(Value::Number(1))),
+                    operator: BinaryOperator::Plus,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                }],
+                negated: false,
+            })
+        )
+    }
+
+    #[test]
+    fn parse_between() {
+        let expr = "price BETWEEN 10 AND 20 AND active = 1";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Between {
+                    expr: Box::new(Expression::Identifier("price".into())),
+                    low: Box::new(Expression::Value(Value::Number(10))),
+                    high: Box::new(Expression::Value(Value::Number(20))),
+                    negated: false,
+                }),
+                operator: BinaryOperator::And,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("active".into())),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn parse_not_between_with_expressions() {
+        let expr = "price NOT BETWEEN 5 * 2 AND 10 + 10 OR id = 1";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Between {
+                    expr: Box::new(Expression::Identifier("price".into())),
+                    low: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Value(Value::Number(5))),
+                        operator: BinaryOperator::Mul,
+                        right: Box::new(Expression::Value(Value::Number(2))),
+                    }),
+                    high: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Value(Value::Number(10))),
+                        operator: BinaryOperator::Plus,
+                        right: Box::new(Expression::Value(Value::Number(10))),
+                    }),
+                    negated: true,
+                }),
+                operator: BinaryOperator::Or,
+                right: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("id".into())),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn parse_is_null() {
+        let expr = "email IS NULL AND name IS NOT NULL";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::IsNull {
+                    expr: Box::new(Expression::Identifier("email".into())),
+                    negated: false,
+                }),
+                operator: BinaryOperator::And,
+                right: Box::new(Expression::IsNull {
+                    expr: Box::new(Expression::Identifier("name".into())),
+                    negated: true,
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn unary_arithmetic_operator_precedence() {
+        let expr = "-2 * -(2 + 2 * 2)";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::UnaryOperation {
+                    operator: UnaryOperator::Minus,
+                    expr: Box::new(Expression::Value(Value::Number(2)))
+                }),
+                operator: BinaryOperator::Mul,
+                right: Box::new(Expression::UnaryOperation {
+                    operator: UnaryOperator::Minus,
+                    expr: Box::new(Expression::Nested(Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Value(Value::Number(2))),
+                        operator: BinaryOperator::Plus,
+                        right: Box::new(Expression::BinaryOperation {
+                            left: Box::new(Expression::Value(Value::Number(2))),
+                            operator: BinaryOperator::Mul,
+                            right: Box::new(Expression::Value(Value::Number(2))),
+                        })
+                    })))
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn parse_select_aggregate_functions() {
+        let sql = "SELECT COUNT(*), SUM(price) FROM products;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![
+                    Expression::Function {
+                        name: "COUNT".into(),
+                        distinct: false,
+                        args: vec![Expression::Wildcard],
+                    }
+                    .into(),
+                    Expression::Function {
+                        name: "SUM".into(),
+                        distinct: false,
+                        args: vec![Expression::Identifier("price".into())],
+                    }
+                    .into()
+                ],
+                from: Some("products".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn parse_count_distinct() {
+        assert_eq!(
+            Parser::new("COUNT(DISTINCT customer_id)").parse_expression(),
+            Ok(Expression::Function {
+                name: "COUNT".into(),
+                distinct: true,
+                args: vec![Expression::Identifier("customer_id".into())],
+            })
+        );
+
+        assert_eq!(
+            Parser::new("COUNT(customer_id)").parse_expression(),
+            Ok(Expression::Function {
+                name: "COUNT".into(),
+                distinct: false,
+                args: vec![Expression::Identifier("customer_id".into())],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_distinct_without_function_arguments() {
+        assert!(matches!(
+            Parser::new("COUNT(DISTINCT)").parse_expression(),
+            Err(ParserError {
+                kind: ErrorKind::ExpectedOneOf {
+                    found: Token::RightParen,
+                    ..
+                },
+                location: Location { line: 1, col: 15 },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_count_distinct_wildcard() {
+        let expr = "COUNT(DISTINCT *)";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Err(ParserError {
+                kind: ErrorKind::Other(
+                    "DISTINCT can't be used with wildcard (*) in function COUNT()".into()
+                ),
+                location: Location { line: 1, col: 17 },
+                end: Location { line: 1, col: 18 },
+                input: expr.into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_function_with_expression_argument() {
+        let expr = "MAX(price * qty) > 100";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::Function {
+                    name: "MAX".into(),
+                    distinct: false,
+                    args: vec![Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("price".into())),
+                        operator: BinaryOperator::Mul,
+                        right: Box::new(Expression::Identifier("qty".into())),
+                    }],
+                }),
+                operator: BinaryOperator::Gt,
+                right: Box::new(Expression::Value(Value::Number(100))),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_wildcard_with_other_function_arguments() {
+        let expr = "COUNT(*, id)";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Err(ParserError {
+                kind: ErrorKind::Other(
+                    "wildcard (*) must be the only argument of function COUNT()".into()
+                ),
+                location: Location { line: 1, col: 12 },
+                end: Location { line: 1, col: 13 },
+                input: expr.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_not() {
+        let expr = "NOT (active = 1)";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::UnaryOperation {
+                operator: UnaryOperator::Not,
+                expr: Box::new(Expression::Nested(Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("active".into())),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::Value(Value::Number(1))),
+                })))
+            })
+        )
+    }
+
+    #[test]
+    fn parse_double_not() {
+        let expr = "NOT NOT active";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::UnaryOperation {
+                operator: UnaryOperator::Not,
+                expr: Box::new(Expression::UnaryOperation {
+                    operator: UnaryOperator::Not,
+                    expr: Box::new(Expression::Identifier("active".into())),
+                })
+            })
+        )
+    }
+
+    #[test]
+    fn not_operator_precedence() {
+        let expr = "NOT a = 1 AND b";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::UnaryOperation {
+                    operator: UnaryOperator::Not,
+                    expr: Box::new(Expression::BinaryOperation {
+                        left: Box::new(Expression::Identifier("a".into())),
+                        operator: BinaryOperator::Eq,
+                        right: Box::new(Expression::Value(Value::Number(1))),
+                    }),
+                }),
+                operator: BinaryOperator::And,
+                right: Box::new(Expression::Identifier("b".into())),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_negative_number() {
+        let sql = "SELECT * FROM accounts WHERE balance = -100;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("accounts".into()),
+                joins: vec![],
+                r#where: Some(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("balance".into())),
+                    operator: BinaryOperator::Eq,
+                    right: Box::new(Expression::UnaryOperation {
+                        operator: UnaryOperator::Minus,
+                        expr: Box::new(Expression::Value(Value::Number(100))),
+                    }),
+                }),
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        )
+    }
+
+    #[test]
+    fn unary_minus_binds_tighter_than_binary_minus() {
+        let expr = "-a + b - -5";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::BinaryOperation {
+                left: Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::UnaryOperation {
+                        operator: UnaryOperator::Minus,
+                        expr: Box::new(Expression::Identifier("a".into())),
+                    }),
+                    operator: BinaryOperator::Plus,
+                    right: Box::new(Expression::Identifier("b".into())),
+                }),
+                operator: BinaryOperator::Minus,
+                right: Box::new(Expression::UnaryOperation {
+                    operator: UnaryOperator::Minus,
+                    expr: Box::new(Expression::Value(Value::Number(5))),
+                }),
+            })
+        )
+    }
+
+    #[test]
+    fn unary_minus_nested() {
+        let expr = "-(a + b)";
+
+        assert_eq!(
+            Parser::new(expr).parse_expression(),
+            Ok(Expression::UnaryOperation {
+                operator: UnaryOperator::Minus,
+                expr: Box::new(Expression::Nested(Box::new(Expression::BinaryOperation {
+                    left: Box::new(Expression::Identifier("a".into())),
+                    operator: BinaryOperator::Plus,
+                    right: Box::new(Expression::Identifier("b".into())),
+                }))),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_unterminated_statement() {
+        let sql = "SELECT * FROM users";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::SemiColon,
+                    found: Token::Eof
+                },
+                location: Location { line: 1, col: 20 },
+                end: Location { line: 1, col: 20 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_missing_final_semicolon() {
+        let sql = "SELECT * FROM t";
+
+        assert_eq!(
+            Parser::new(sql)
+                .allow_missing_final_semicolon(true)
+                .parse_statement(),
+            Ok(Statement::Select(Box::new(Select {
+                ctes: vec![],
+                distinct: false,
+                columns: vec![Expression::Wildcard.into()],
+                from: Some("t".into()),
+                joins: vec![],
+                r#where: None,
+                group_by: vec![],
+                having: None,
+                order_by: vec![],
+                limit: None,
+                offset: None
+            })))
+        );
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::SemiColon,
+                    found: Token::Eof
+                },
+                location: Location { line: 1, col: 16 },
+                end: Location { line: 1, col: 16 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn missing_semicolon_only_allowed_at_the_end() {
+        let parse = |sql| {
+            Parser::new(sql)
+                .allow_missing_final_semicolon(true)
+                .try_parse()
+        };
+
+        assert_eq!(
+            parse("COMMIT; ROLLBACK"),
+            Ok(vec![Statement::Commit, Statement::Rollback])
+        );
+
+        assert!(parse("COMMIT ROLLBACK;").is_err());
+    }
+
+    #[test]
+    fn parse_decimal_scale_greater_than_precision() {
+        let sql = "CREATE TABLE accounts (balance DECIMAL(2, 10));";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Create(Create::Database("test".into())))
+            Err(ParserError {
+                kind: ErrorKind::Other(
+                    "DECIMAL scale 10 cannot be greater than precision 2".into()
+                ),
+                location: Location { line: 1, col: 45 },
+                end: Location { line: 1, col: 46 },
+                input: sql.to_owned(),
+            })
         )
     }
 
     #[test]
-    fn parse_create_table() {
-        let sql = r#"
-            CREATE TABLE users (
-                id INT PRIMARY KEY,
-                name VARCHAR(255),
-                email VARCHAR(255) UNIQUE
-            );
-        "#;
+    fn parse_incomplete_not_null_constraint() {
+        let sql = "CREATE TABLE users (id INT NOT UNIQUE);";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Create(Create::Table {
-                name: "users".into(),
-                columns: vec![
-                    Column::primary_key("id", DataType::Int),
-                    Column::new("name", DataType::Varchar(255)),
-                    Column::unique("email", DataType::Varchar(255)),
-                ]
-            }))
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Keyword(Keyword::Null),
+                    found: Token::Keyword(Keyword::Unique)
+                },
+                location: Location { line: 1, col: 32 },
+                end: Location { line: 1, col: 38 },
+                input: sql.to_owned(),
+            })
         )
     }
 
     #[test]
-    fn parse_create_index() {
-        let sql = "CREATE INDEX test_idx ON test(some_column);";
+    fn parse_table_constraints_with_different_case() {
+        let sql =
+            "CREATE TABLE orders (Id INT, Code VARCHAR(16), PRIMARY KEY (id), UNIQUE (CODE));";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Create(Create::Index {
-                name: "test_idx".into(),
-                table: "test".into(),
-                column: "some_column".into(),
-                unique: false,
+            Ok(Statement::Create(Create::Table {
+                name: "orders".into(),
+                columns: vec![
+                    Column::primary_key("Id", DataType::Int),
+                    Column::unique("Code", DataType::Varchar(16)),
+                ],
+                table_constraints: vec![],
+                if_not_exists: false,
             }))
         )
     }
 
     #[test]
-    fn parse_create_unique_index() {
-        let sql = "CREATE UNIQUE INDEX email_uq_idx ON users(email);";
+    fn parse_table_constraint_on_unknown_column() {
+        let sql = "CREATE TABLE orders (id INT, FOREIGN KEY (user_id) REFERENCES users(id));";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Create(Create::Index {
-                name: "email_uq_idx".into(),
-                table: "users".into(),
-                column: "email".into(),
-                unique: true,
-            }))
+            Err(ParserError {
+                kind: ErrorKind::Other("table constraint references unknown column user_id".into()),
+                location: Location { line: 1, col: 72 },
+                end: Location { line: 1, col: 73 },
+                input: sql.to_owned(),
+            })
         )
     }
 
     #[test]
-    fn parse_simple_update() {
-        let sql = "UPDATE users SET is_admin = 1;";
+    fn parse_duplicate_column_names() {
+        let sql = "CREATE TABLE users (id INT, name VARCHAR(255), ID INT UNIQUE);";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Update {
-                table: "users".into(),
-                columns: vec![Assignment {
-                    identifier: "is_admin".into(),
-                    value: Expression::Value(Value::Number(1)),
-                }],
-                r#where: None,
+            Err(ParserError {
+                kind: ErrorKind::Other("column 'ID' specified more than once".into()),
+                location: Location { line: 1, col: 48 },
+                end: Location { line: 1, col: 61 },
+                input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn parse_update_where() {
-        let sql = r#"
-            UPDATE products
-            SET price = price - 10, discount = 15, stock = 10
-            WHERE price > 100;
-        "#;
+    fn parse_distinct_column_names() {
+        let sql = "CREATE TABLE users (id INT, user_id INT, name VARCHAR(255));";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Update {
-                table: "products".into(),
+            Ok(Statement::Create(Create::Table {
+                name: "users".into(),
                 columns: vec![
-                    Assignment {
-                        identifier: "price".into(),
-                        value: Expression::BinaryOperation {
-                            left: Box::new(Expression::Identifier("price".into())),
-                            operator: BinaryOperator::Minus,
-                            right: Box::new(Expression::Value(Value::Number(10))),
-                        }
-                    },
-                    Assignment {
-                        identifier: "discount".into(),
-                        value: Expression::Value(Value::Number(15))
-                    },
-                    Assignment {
-                        identifier: "stock".into(),
-                        value: Expression::Value(Value::Number(10))
-                    }
+                    Column::new("id", DataType::Int),
+                    Column::new("user_id", DataType::Int),
+                    Column::new("name", DataType::Varchar(255)),
                 ],
-                r#where: Some(Expression::BinaryOperation {
-                    left: Box::new(Expression::Identifier("price".into())),
-                    operator: BinaryOperator::Gt,
-                    right: Box::new(Expression::Value(Value::Number(100))),
-                })
-            })
+                table_constraints: vec![],
+                if_not_exists: false,
+            }))
         )
     }
 
     #[test]
-    fn parse_delete_from() {
-        let sql = "DELETE FROM products;";
+    fn parse_multiple_primary_keys() {
+        let sql = "CREATE TABLE t (a INT PRIMARY KEY, b INT PRIMARY KEY);";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Delete {
-                from: "products".into(),
-                r#where: None
+            Err(ParserError {
+                kind: ErrorKind::Other("only one primary key per table is allowed".into()),
+                location: Location { line: 1, col: 36 },
+                end: Location { line: 1, col: 53 },
+                input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn parse_delete_from_where() {
-        let sql = "DELETE FROM products WHERE price > 5000;";
+    fn parse_primary_key_column_and_table_constraint() {
+        let sql = "CREATE TABLE t (a INT PRIMARY KEY, b INT, PRIMARY KEY (a, b));";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Delete {
-                from: "products".into(),
-                r#where: Some(Expression::BinaryOperation {
-                    left: Box::new(Expression::Identifier("price".into())),
-                    operator: BinaryOperator::Gt,
-                    right: Box::new(Expression::Value(Value::Number(5000))),
-                })
+            Err(ParserError {
+                kind: ErrorKind::Other("only one primary key per table is allowed".into()),
+                location: Location { line: 1, col: 43 },
+                end: Location { line: 1, col: 61 },
+                input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn parse_insert_into() {
-        let sql = r#"INSERT INTO users (id, name, email) VALUES (1, "Test", "test@test.com");"#;
+    fn parse_composite_primary_key_counts_as_one() {
+        let sql =
+            "CREATE TABLE follows (follower INT, followed INT, PRIMARY KEY (follower, followed));";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
-            Ok(Statement::Insert {
-                into: "users".into(),
-                columns: ["id", "name", "email"].map(String::from).into(),
-                values: vec![
-                    Expression::Value(Value::Number(1)),
-                    Expression::Value(Value::String("Test".into())),
-                    Expression::Value(Value::String("test@test.com".into())),
-                ]
-            })
-        );
+            Ok(Statement::Create(Create::Table {
+                name: "follows".into(),
+                columns: vec![
+                    Column::new("follower", DataType::Int),
+                    Column::new("followed", DataType::Int),
+                ],
+                table_constraints: vec![TableConstraint::PrimaryKey(vec![
+                    "follower".into(),
+                    "followed".into()
+                ])],
+                if_not_exists: false,
+            }))
+        )
     }
 
     #[test]
-    fn parse_insert_into_optional_columns() {
-        let sql = r#"INSERT INTO users VALUES (1, "Test", "test@test.com");"#;
+    fn deeply_nested_expression() {
+        let sql = format!("SELECT {}1{};", "(".repeat(10_000), ")".repeat(10_000));
 
         assert_eq!(
-            Parser::new(sql).parse_statement(),
-            Ok(Statement::Insert {
-                into: "users".into(),
-                columns: vec![],
-                values: vec![
-                    Expression::Value(Value::Number(1)),
-                    Expression::Value(Value::String("Test".into())),
-                    Expression::Value(Value::String("test@test.com".into())),
-                ]
+            Parser::new(&sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::RecursionLimitExceeded(DEFAULT_MAX_DEPTH),
+                // The query itself takes the first level.
+                location: Location {
+                    line: 1,
+                    col: "SELECT ".len() + DEFAULT_MAX_DEPTH - 1,
+                },
+                end: Location {
+                    line: 1,
+                    col: "SELECT ".len() + DEFAULT_MAX_DEPTH,
+                },
+                input: sql.clone(),
             })
         );
     }
 
     #[test]
-    fn parse_drop_database() {
-        let sql = "DROP DATABASE test;";
+    fn deeply_nested_derived_tables() {
+        let sql = format!(
+            "SELECT * FROM {}t{};",
+            "(SELECT * FROM ".repeat(50_000),
+            ") AS x".repeat(50_000)
+        );
 
         assert_eq!(
-            Parser::new(sql).parse_statement(),
-            Ok(Statement::Drop(Drop::Database("test".into())))
-        )
+            Parser::new(&sql).parse_statement().map_err(|e| e.kind),
+            Err(ErrorKind::RecursionLimitExceeded(DEFAULT_MAX_DEPTH))
+        );
     }
 
     #[test]
-    fn parse_drop_table() {
-        let sql = "DROP TABLE test;";
+    fn deeply_nested_subqueries() {
+        let sql = format!(
+            "SELECT {}1{};",
+            "(SELECT ".repeat(50_000),
+            ")".repeat(50_000)
+        );
 
         assert_eq!(
-            Parser::new(sql).parse_statement(),
-            Ok(Statement::Drop(Drop::Table("test".into())))
-        )
+            Parser::new(&sql).parse_statement().map_err(|e| e.kind),
+            Err(ErrorKind::RecursionLimitExceeded(DEFAULT_MAX_DEPTH))
+        );
     }
 
     #[test]
-    fn parse_multiple_statements() {
-        let sql = r#"
-            DROP TABLE test;
-            UPDATE users SET is_admin = 1;
-            SELECT * FROM products;
-        "#;
+    fn deeply_nested_explain() {
+        let sql = "EXPLAIN ".repeat(50_000) + "SELECT 1;";
 
         assert_eq!(
-            Parser::new(sql).try_parse(),
-            Ok(vec![
-                Statement::Drop(Drop::Table("test".into())),
-                Statement::Update {
-                    table: "users".into(),
-                    columns: vec![Assignment {
-                        identifier: "is_admin".into(),
-                        value: Expression::Value(Value::Number(1)),
-                    }],
-                    r#where: None,
-                },
-                Statement::Select {
-                    columns: vec![Expression::Wildcard],
-                    from: "products".into(),
-                    r#where: None,
-                    order_by: vec![],
-                }
-            ])
-        )
+            Parser::new(&sql).parse_statement().map_err(|e| e.kind),
+            Err(ErrorKind::RecursionLimitExceeded(DEFAULT_MAX_DEPTH))
+        );
     }
 
     #[test]
-    fn parse_explain() {
-        let sql = "EXPLAIN SELECT name, email FROM users ORDER BY email;";
+    fn configurable_max_depth() {
+        let nested = |depth| "- ".repeat(depth) + "1";
 
+        assert!(Parser::new(&nested(9))
+            .max_depth(10)
+            .parse_expression()
+            .is_ok());
         assert_eq!(
-            Parser::new(sql).parse_statement(),
-            Ok(Statement::Explain(Box::new(Statement::Select {
-                columns: vec![
-                    Expression::Identifier("name".into()),
-                    Expression::Identifier("email".into())
-                ],
-                from: "users".into(),
-                r#where: None,
-                order_by: vec![Expression::Identifier("email".into())]
-            })))
-        )
+            Parser::new(&nested(10))
+                .max_depth(10)
+                .parse_expression()
+                .map_err(|e| e.kind),
+            Err(ErrorKind::RecursionLimitExceeded(10))
+        );
     }
 
     #[test]
-    fn arithmetic_operator_precedence() {
-        let expr = "price * discount / 100 < 10 + 20 * 30";
+    fn parse_check_without_parenthesis() {
+        let sql = "CREATE TABLE products (price INT CHECK price >= 0);";
 
         assert_eq!(
-            Parser::new(expr).parse_expression(),
-            Ok(Expression::BinaryOperation {
-                left: Box::new(Expression::BinaryOperation {
-                    left: Box::new(Expression::BinaryOperation {
-                        left: Box::new(Expression::Identifier("price".into())),
-                        operator: BinaryOperator::Mul,
-                        right: Box::new(Expression::Identifier("discount".into())),
-                    }),
-                    operator: BinaryOperator::Div,
-                    right: Box::new(Expression::Value(Value::Number(100))),
-                }),
-                operator: BinaryOperator::Lt,
-                right: Box::new(Expression::BinaryOperation {
-                    left: Box::new(Expression::Value(Value::Number(10))),
-                    operator: BinaryOperator::Plus,
-                    right: Box::new(Expression::BinaryOperation {
-                        left: Box::new(Expression::Value(Value::Number(20))),
-                        operator: BinaryOperator::Mul,
-                        right: Box::new(Expression::Value(Value::Number(30))),
-                    })
-                })
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::LeftParen,
+                    found: Token::Identifier("price".into())
+                },
+                location: Location { line: 1, col: 40 },
+                end: Location { line: 1, col: 45 },
+                input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn nested_arithmetic_precedence() {
-        let expr = "price * discount >= 10 - (20 + 50) / (2 * (4 + (1 - 1)))";
+    fn parse_auto_increment_on_non_integer_column() {
+        let sql = "CREATE TABLE users (name VARCHAR(10) AUTO_INCREMENT);";
 
         assert_eq!(
-            Parser::new(expr).parse_expression(),
-            Ok(Expression::BinaryOperation {
-                left: Box::new(Expression::BinaryOperation {
-                    left: Box::new(Expression::Identifier("price".into())),
-                    operator: BinaryOperator::Mul,
-                    right: Box::new(Expression::Identifier("discount".into())),
-                }),
-                operator: BinaryOperator::GtEq,
-                right: Box::new(Expression::BinaryOperation {
-                    left: Box::new(Expression::Value(Value::Number(10))),
-                    operator: BinaryOperator::Minus,
-                    right: Box::new(Expression::BinaryOperation {
-                        left: Box::new(Expression::Nested(Box::new(Expression::BinaryOperation {
-                            left: Box::new(Expression::Value(Value::Number(20))),
-                            operator: BinaryOperator::Plus,
-                            right: Box::new(Expression::Value(Value::Number(50))),
-                        }))),
-                        operator: BinaryOperator::Div,
-                        right: Box::new(Expression::Nested(Box::new(
-                            Expression::BinaryOperation {
-                                left: Box::new(Expression::Value(Value::Number(2))),
-                                operator: BinaryOperator::Mul,
-                                right: Box::new(Expression::Nested(Box::new(
-                                    Expression::BinaryOperation {
-                                        left: Box::new(Expression::Value(Value::Number(4))),
-                                        operator: BinaryOperator::Plus,
-                                        right: Box::new(Expression::Nested(Box::new(
-                                            Expression::BinaryOperation {
-                                                left: Box::new(Expression::Value(Value::Number(1))),
-                                                operator: BinaryOperator::Minus,
-                                                right: Box::new(Expression::Value(Value::Number(
-                                                    1
-                                                ))),
-                                            }
-                                        )))
-                                    }
-                                )))
-                            }
-                        )))
-                    })
-                })
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Other(
+                    "AUTO_INCREMENT is not allowed on VARCHAR(10) columns".into()
+                ),
+                location: Location { line: 1, col: 38 },
+                end: Location { line: 1, col: 52 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_partial_select() {
+        let sql = "SELECT";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::ExpectedOneOf {
+                    expected: vec![
+                        Token::Identifier(Default::default()),
+                        Token::Number(Default::default()),
+                        Token::String(Default::default()),
+                        Token::Mul,
+                        Token::Minus,
+                        Token::Plus,
+                        Token::LeftParen
+                    ],
+                    found: Token::Eof
+                },
+                location: Location { line: 1, col: 7 },
+                end: Location { line: 1, col: 7 },
+                input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn and_or_operators_precedence() {
-        let expr = "100 <= price AND price <= 200 OR price > 1000";
+    fn parse_limit_without_value() {
+        let sql = "SELECT * FROM users LIMIT;";
 
         assert_eq!(
-            Parser::new(expr).parse_expression(),
-            Ok(Expression::BinaryOperation {
-                left: Box::new(Expression::BinaryOperation {
-                    left: Box::new(Expression::BinaryOperation {
-                        left: Box::new(Expression::Value(Value::Number(100))),
-                        operator: BinaryOperator::LtEq,
-                        right: Box::new(Expression::Identifier("price".into())),
-                    }),
-                    operator: BinaryOperator::And,
-                    right: Box::new(Expression::BinaryOperation {
-                        left: Box::new(Expression::Identifier("price".into())),
-                        operator: BinaryOperator::LtEq,
-                        right: Box::new(Expression::Value(Value::Number(200))),
-                    }),
-                }),
-                operator: BinaryOperator::Or,
-                right: Box::new(Expression::BinaryOperation {
-                    left: Box::new(Expression::Identifier("price".into())),
-                    operator: BinaryOperator::Gt,
-                    right: Box::new(Expression::Value(Value::Number(1000))),
-                })
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::ExpectedOneOf {
+                    expected: vec![
+                        Token::Identifier(Default::default()),
+                        Token::Number(Default::default()),
+                        Token::String(Default::default()),
+                        Token::Mul,
+                        Token::Minus,
+                        Token::Plus,
+                        Token::LeftParen
+                    ],
+                    found: Token::SemiColon
+                },
+                location: Location { line: 1, col: 26 },
+                end: Location { line: 1, col: 27 },
+                input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn unary_arithmetic_operator_precedence() {
-        let expr = "-2 * -(2 + 2 * 2)";
+    fn parse_group_by_after_order_by() {
+        let sql = "SELECT age FROM users ORDER BY age GROUP BY age;";
 
         assert_eq!(
-            Parser::new(expr).parse_expression(),
-            Ok(Expression::BinaryOperation {
-                left: Box::new(Expression::UnaryOperation {
-                    operator: UnaryOperator::Minus,
-                    expr: Box::new(Expression::Value(Value::Number(2)))
-                }),
-                operator: BinaryOperator::Mul,
-                right: Box::new(Expression::UnaryOperation {
-                    operator: UnaryOperator::Minus,
-                    expr: Box::new(Expression::Nested(Box::new(Expression::BinaryOperation {
-                        left: Box::new(Expression::Value(Value::Number(2))),
-                        operator: BinaryOperator::Plus,
-                        right: Box::new(Expression::BinaryOperation {
-                            left: Box::new(Expression::Value(Value::Number(2))),
-                            operator: BinaryOperator::Mul,
-                            right: Box::new(Expression::Value(Value::Number(2))),
-                        })
-                    })))
-                })
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Other("GROUP BY clause must appear before ORDER BY".into()),
+                location: Location { line: 1, col: 36 },
+                end: Location { line: 1, col: 41 },
+                input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn parse_unterminated_statement() {
-        let sql = "SELECT * FROM users";
+    fn parse_where_after_group_by() {
+        let sql = "SELECT age FROM users GROUP BY age WHERE age > 18;";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
             Err(ParserError {
-                kind: ErrorKind::Expected {
-                    expected: Token::SemiColon,
-                    found: Token::Eof
-                },
-                location: Location { line: 1, col: 20 },
+                kind: ErrorKind::Other("WHERE clause must appear before GROUP BY".into()),
+                location: Location { line: 1, col: 36 },
+                end: Location { line: 1, col: 41 },
                 input: sql.to_owned(),
             })
         )
     }
 
     #[test]
-    fn parse_partial_select() {
-        let sql = "SELECT";
+    fn parse_having_without_group_by() {
+        let sql = "SELECT age FROM users HAVING age > 18;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Other("HAVING clause requires a GROUP BY clause".into()),
+                location: Location { line: 1, col: 23 },
+                end: Location { line: 1, col: 29 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn parse_distinct_as_column() {
+        let sql = "SELECT distinct FROM users;";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
@@ -1377,9 +7364,10 @@ mod tests {
                         Token::Plus,
                         Token::LeftParen
                     ],
-                    found: Token::Eof
+                    found: Token::Keyword(Keyword::From)
                 },
-                location: Location { line: 1, col: 7 },
+                location: Location { line: 1, col: 17 },
+                end: Location { line: 1, col: 21 },
                 input: sql.to_owned(),
             })
         )
@@ -1397,6 +7385,7 @@ mod tests {
                     found: Token::Eof
                 },
                 location: Location { line: 1, col: 7 },
+                end: Location { line: 1, col: 7 },
                 input: sql.to_owned(),
             })
         )
@@ -1414,11 +7403,63 @@ mod tests {
                     found: Token::Div,
                 },
                 location: Location { line: 1, col: 1 },
+                end: Location { line: 1, col: 2 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn suggest_keyword_for_misspelled_statement() {
+        for (sql, found, suggestion) in [
+            ("SELCT * FROM users;", "SELCT", Keyword::Select),
+            ("UPDTE users SET id = 1;", "UPDTE", Keyword::Update),
+        ] {
+            let error = Parser::new(sql).parse_statement().unwrap_err();
+
+            assert_eq!(error, ParserError {
+                kind: ErrorKind::UnknownStatement {
+                    found: found.into(),
+                    suggestion,
+                },
+                location: Location { line: 1, col: 1 },
+                end: Location { line: 1, col: 6 },
+                input: sql.to_owned(),
+            });
+
+            assert!(error
+                .to_string()
+                .contains(&format!("did you mean {suggestion}?")));
+        }
+    }
+
+    #[test]
+    fn no_suggestion_for_unrelated_identifier() {
+        let sql = "FOOBAR * FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::ExpectedOneOf {
+                    expected: Parser::tokens_from_keywords(&Parser::supported_statements()),
+                    found: Token::Identifier("FOOBAR".into()),
+                },
+                location: Location { line: 1, col: 1 },
+                end: Location { line: 1, col: 7 },
                 input: sql.to_owned(),
             })
         )
     }
 
+    #[test]
+    fn levenshtein_distance_between_strings() {
+        assert_eq!(levenshtein_distance("SELECT", "SELECT"), 0);
+        assert_eq!(levenshtein_distance("SELCT", "SELECT"), 1);
+        assert_eq!(levenshtein_distance("UPDTE", "UPDATE"), 1);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(levenshtein_distance("", "DROP"), 4);
+    }
+
     #[test]
     fn parse_unexpected_initial_keyword() {
         let sql = "VARCHAR * FROM users;";
@@ -1431,6 +7472,7 @@ mod tests {
                     found: Token::Keyword(Keyword::Varchar),
                 },
                 location: Location { line: 1, col: 1 },
+                end: Location { line: 1, col: 8 },
                 input: sql.to_owned(),
             }),
         )
@@ -1456,6 +7498,7 @@ mod tests {
                     found: Token::RightParen,
                 },
                 location: Location { line: 1, col: 8 },
+                end: Location { line: 1, col: 9 },
                 input: sql.to_owned(),
             })
         )
@@ -1463,16 +7506,17 @@ mod tests {
 
     #[test]
     fn expect_keyword() {
-        let sql = "SELECT * VALUES users";
+        let sql = "INSERT users VALUES (1);";
 
         assert_eq!(
             Parser::new(sql).parse_statement(),
             Err(ParserError {
                 kind: ErrorKind::Expected {
-                    expected: Token::Keyword(Keyword::From),
-                    found: Token::Keyword(Keyword::Values)
+                    expected: Token::Keyword(Keyword::Into),
+                    found: Token::Identifier("users".into())
                 },
-                location: Location { line: 1, col: 10 },
+                location: Location { line: 1, col: 8 },
+                end: Location { line: 1, col: 13 },
                 input: sql.to_owned(),
             })
         )
@@ -1489,10 +7533,13 @@ mod tests {
                     expected: vec![
                         Token::Keyword(Keyword::Database),
                         Token::Keyword(Keyword::Table),
+                        Token::Keyword(Keyword::Index),
+                        Token::Keyword(Keyword::View),
                     ],
                     found: Token::Keyword(Keyword::Values)
                 },
                 location: Location { line: 1, col: 6 },
+                end: Location { line: 1, col: 12 },
                 input: sql.to_owned(),
             })
         )
@@ -1510,6 +7557,7 @@ mod tests {
                     found: Token::Identifier("INCORRECT".into())
                 },
                 location: Location { line: 1, col: 23 },
+                end: Location { line: 1, col: 32 },
                 input: sql.to_owned(),
             })
         )
@@ -1527,11 +7575,277 @@ mod tests {
                     found: Token::Number("1".into())
                 },
                 location: Location { line: 1, col: 13 },
+                end: Location { line: 1, col: 14 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn expect_identifier_after_drop_column() {
+        let sql = "ALTER TABLE users DROP COLUMN;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Identifier(Default::default()),
+                    found: Token::SemiColon
+                },
+                location: Location { line: 1, col: 30 },
+                end: Location { line: 1, col: 31 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn expect_identifier_after_dot() {
+        let sql = "SELECT users.'id' FROM users;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Identifier(Default::default()),
+                    found: Token::String("id".into())
+                },
+                location: Location { line: 1, col: 14 },
+                end: Location { line: 1, col: 18 },
                 input: sql.to_owned(),
             })
         )
     }
 
+    #[test]
+    fn derived_table_without_alias() {
+        let sql = "SELECT * FROM (SELECT id FROM users) WHERE id = 1;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Other("subqueries in FROM must have an alias".into()),
+                location: Location { line: 1, col: 37 },
+                end: Location { line: 1, col: 38 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn cast_requires_as() {
+        let sql = "SELECT CAST(price INT) FROM products;";
+
+        assert_eq!(
+            Parser::new(sql).parse_statement(),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Keyword(Keyword::As),
+                    found: Token::Keyword(Keyword::Int)
+                },
+                location: Location { line: 1, col: 19 },
+                end: Location { line: 1, col: 22 },
+                input: sql.to_owned(),
+            })
+        )
+    }
+
+    #[test]
+    fn error_span_covers_whole_identifier() {
+        let sql = "DELETE customers WHERE id = 1;";
+
+        let error = Parser::new(sql).parse_statement().unwrap_err();
+
+        assert_eq!(error, ParserError {
+            kind: ErrorKind::Expected {
+                expected: Token::Keyword(Keyword::From),
+                found: Token::Identifier("customers".into()),
+            },
+            location: Location { line: 1, col: 8 },
+            end: Location { line: 1, col: 17 },
+            input: sql.to_owned(),
+        });
+
+        assert_eq!(error.to_string().lines().last(), Some("       ^^^^^^^^^"));
+    }
+
+    #[test]
+    fn display_error_with_location() {
+        let sql = "SELECT id\nFROM users\nWHERE id = = 1;";
+
+        let error = Parser::new(sql).parse_statement().unwrap_err();
+
+        assert_eq!(
+            error.to_string().lines().next(),
+            Some(
+                "Parse Error at line 3 column 12: expected identifier, number, string, '*', '-', '+' or '('. Found '=' instead"
+            )
+        );
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn tokenizer_error_as_source() {
+        let sql = "SELECT * FROM users WHERE name = 'not closed";
+
+        let error = Parser::new(sql).parse_statement().unwrap_err();
+
+        assert_eq!(
+            error.to_string().lines().next(),
+            Some("Parse Error at line 1 column 45: string not closed")
+        );
+        assert_eq!(
+            error.source().map(ToString::to_string),
+            Some("string not closed".into())
+        );
+    }
+
+    #[test]
+    fn render_error_mid_statement() {
+        let sql = "SELECT id, name\nFROM users\nWHERE id = = 1;";
+
+        let error = Parser::new(sql).parse_statement().unwrap_err();
+
+        assert_eq!(
+            error.render(sql),
+            [
+                "error: expected identifier, number, string, '*', '-', '+' or '('. Found '=' instead",
+                " --> line 3, column 12",
+                "  |",
+                "3 | WHERE id = = 1;",
+                "  |            ^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn render_error_span() {
+        let sql = "DELETE customers WHERE id = 1;";
+
+        let error = Parser::new(sql).parse_statement().unwrap_err();
+
+        assert_eq!(
+            error.render(sql),
+            [
+                "error: expected 'FROM', found 'customers' instead",
+                " --> line 1, column 8",
+                "  |",
+                "1 | DELETE customers WHERE id = 1;",
+                "  |        ^^^^^^^^^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn render_unexpected_eof() {
+        let sql = "SELECT * FROM users\nWHERE";
+
+        let error = Parser::new(sql).parse_statement().unwrap_err();
+
+        assert_eq!(
+            error.render(sql),
+            [
+                "error: expected identifier, number, string, '*', '-', '+' or '('. Found 'EOF' instead",
+                " --> line 2, column 6",
+                "  |",
+                "2 | WHERE",
+                "  |      ^",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn parse_single_expression() {
+        assert_eq!(
+            Parser::parse_single_expression("  age >= 18 AND name LIKE 'J%'\n"),
+            Parser::new("age >= 18 AND name LIKE 'J%'").parse_expression()
+        );
+    }
+
+    #[test]
+    fn parse_single_expression_with_trailing_tokens() {
+        let input = "age >= 18 name";
+
+        assert_eq!(
+            Parser::parse_single_expression(input),
+            Err(ParserError {
+                kind: ErrorKind::Expected {
+                    expected: Token::Eof,
+                    found: Token::Identifier("name".into()),
+                },
+                location: Location { line: 1, col: 11 },
+                end: Location { line: 1, col: 15 },
+                input: input.into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_single_expression_rejects_semicolon() {
+        assert!(Parser::parse_single_expression("1 + 1;").is_err());
+    }
+
+    #[test]
+    fn varchar_length_out_of_bounds() {
+        for (sql, length, col, end) in [
+            ("CREATE TABLE t (name VARCHAR(0));", "0", 30, 31),
+            ("CREATE TABLE t (name VARCHAR(65536));", "65536", 30, 35),
+            (
+                "CREATE TABLE t (name VARCHAR(999999999999));",
+                "999999999999",
+                30,
+                42,
+            ),
+        ] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Err(ParserError {
+                    kind: ErrorKind::Other(format!(
+                        "VARCHAR length must be between 1 and 65535, found {length}"
+                    )),
+                    location: Location { line: 1, col },
+                    end: Location { line: 1, col: end },
+                    input: sql.to_owned(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn varchar_length_within_bounds() {
+        for (sql, length) in [
+            ("CREATE TABLE t (name VARCHAR(1));", 1),
+            ("CREATE TABLE t (name VARCHAR(65535));", 65535),
+        ] {
+            assert_eq!(
+                Parser::new(sql).parse_statement(),
+                Ok(Statement::Create(Create::Table {
+                    name: "t".into(),
+                    columns: vec![Column::new("name", DataType::Varchar(length))],
+                    table_constraints: vec![],
+                    if_not_exists: false,
+                }))
+            );
+        }
+    }
+
+    #[test]
+    fn configurable_max_varchar_length() {
+        let sql = "CREATE TABLE t (name VARCHAR(100));";
+
+        assert!(Parser::new(sql)
+            .max_varchar_length(99)
+            .parse_statement()
+            .is_err());
+        assert!(Parser::new(sql)
+            .max_varchar_length(100)
+            .parse_statement()
+            .is_ok());
+    }
+
     #[test]
     fn expect_varchar_length() {
         let sql = "CREATE TABLE test (name VARCHAR(test));";
@@ -1544,6 +7858,7 @@ mod tests {
                     found: Token::Identifier("test".into())
                 },
                 location: Location { line: 1, col: 33 },
+                end: Location { line: 1, col: 37 },
                 input: sql.to_owned(),
             })
         )
@@ -1561,6 +7876,7 @@ mod tests {
                     found: Token::Identifier("id".into())
                 },
                 location: Location { line: 1, col: 20 },
+                end: Location { line: 1, col: 22 },
                 input: sql.to_owned(),
             })
         )
@@ -1,6 +1,8 @@
 // Final step in the SQL pipeline before plan generation.
 
-use super::statement::{Expression, Statement, Value};
+use super::statement::{
+    Expression, InsertSource, Select, SelectItem, Statement, TableReference, Value,
+};
 use crate::db::{DatabaseContext, DbError, ROW_ID_COL};
 
 /// Takes a statement and prepares it for plan generation.
@@ -39,44 +41,52 @@ use crate::db::{DatabaseContext, DbError, ROW_ID_COL};
 /// Also prepends the "row_id" column and value in the insert statement. Not
 /// sure if we should do that now or wait until we execute the plan.
 ///
-/// Additionally, we should deal with default values and auto-increment keys or
-/// stuff like that here.
+/// Omitted columns that have a `DEFAULT` constraint are filled in with their
+/// default value. Auto-increment keys or stuff like that should also be dealt
+/// with here.
 pub(crate) fn prepare(
     statement: &mut Statement,
     ctx: &mut impl DatabaseContext,
 ) -> Result<(), DbError> {
     match statement {
-        Statement::Select { columns, from, .. }
-            if columns.iter().any(|expr| *expr == Expression::Wildcard) =>
-        {
-            let metadata = ctx.table_metadata(from)?;
-
-            let identifiers = metadata
-                .schema
-                .columns
-                .iter()
-                .filter(|&col| col.name != ROW_ID_COL)
-                .cloned()
-                .map(|col| Expression::Identifier(col.name))
-                .collect::<Vec<Expression>>();
-
-            let mut resolved_wildcards = Vec::new();
-
-            for expr in columns.drain(..) {
-                if expr == Expression::Wildcard {
-                    resolved_wildcards.extend(identifiers.iter().cloned());
-                } else {
-                    resolved_wildcards.push(expr);
+        Statement::Select(select) => match &mut **select {
+            Select {
+                columns,
+                from: Some(TableReference::Table { name: from, .. }),
+                ..
+            } if columns.iter().any(|item| item.expr == Expression::Wildcard) => {
+                let metadata = ctx.table_metadata(from)?;
+
+                let identifiers = metadata
+                    .schema
+                    .columns
+                    .iter()
+                    .filter(|&col| col.name != ROW_ID_COL)
+                    .cloned()
+                    .map(|col| SelectItem::from(Expression::Identifier(col.name)))
+                    .collect::<Vec<SelectItem>>();
+
+                let mut resolved_wildcards = Vec::new();
+
+                for item in columns.drain(..) {
+                    if item.expr == Expression::Wildcard {
+                        resolved_wildcards.extend(identifiers.iter().cloned());
+                    } else {
+                        resolved_wildcards.push(item);
+                    }
                 }
+
+                *columns = resolved_wildcards;
             }
 
-            *columns = resolved_wildcards;
-        }
+            _ => {}
+        },
 
         Statement::Insert {
             into,
             columns,
-            values,
+            source: InsertSource::Values(rows),
+            ..
         } => {
             let metadata = ctx.table_metadata(into)?;
 
@@ -86,18 +96,42 @@ pub(crate) fn prepare(
                 *columns = metadata.schema.column_identifiers();
             }
 
+            // Fill omitted columns with their default values. The analyzer
+            // has already checked that all of them have one.
+            for col in &metadata.schema.columns {
+                if let Some(default) = col.default_value() {
+                    if !columns.contains(&col.name) {
+                        columns.push(col.name.clone());
+                        for values in rows.iter_mut() {
+                            values.push(default.clone());
+                        }
+                    }
+                }
+            }
+
             if metadata.schema.columns[0].name == ROW_ID_COL {
                 if columns[0] != ROW_ID_COL {
                     columns.insert(0, ROW_ID_COL.into());
                 }
-                let row_id = metadata.next_row_id();
-                values.insert(0, Expression::Value(Value::Number(row_id.into())));
+                for values in rows.iter_mut() {
+                    let row_id = metadata.next_row_id();
+                    values.insert(0, Expression::Value(Value::Number(row_id.into())));
+                }
             }
 
+            // Keep swapping until each position holds its own column, a single
+            // swap per position doesn't sort every permutation.
             for current_index in 0..metadata.schema.len() {
-                let sorted_index = metadata.schema.index_of(&columns[current_index]).unwrap();
-                columns.swap(current_index, sorted_index);
-                values.swap(current_index, sorted_index);
+                loop {
+                    let sorted_index = metadata.schema.index_of(&columns[current_index]).unwrap();
+                    if sorted_index == current_index {
+                        break;
+                    }
+                    columns.swap(current_index, sorted_index);
+                    for values in rows.iter_mut() {
+                        values.swap(current_index, sorted_index);
+                    }
+                }
             }
         }
 
@@ -165,4 +199,22 @@ mod tests {
             prepared: "INSERT INTO users(row_id, id, name, age, email) VALUES (1, 1, 'John Doe', 20, 'john@mail.com');"
         })
     }
+
+    #[test]
+    fn prepare_insert_statement_with_rotated_columns() -> Result<(), DbError> {
+        assert_prep(Prep {
+            setup: &["CREATE TABLE t (a INT, b INT, c INT, d INT);"],
+            raw_stmt: "INSERT INTO t(b, c, d, a) VALUES (2, 3, 4, 1);",
+            prepared: "INSERT INTO t(row_id, a, b, c, d) VALUES (1, 1, 2, 3, 4);",
+        })
+    }
+
+    #[test]
+    fn prepare_insert_statement_with_default_values() -> Result<(), DbError> {
+        assert_prep(Prep {
+            setup: &["CREATE TABLE users (id INT PRIMARY KEY, name VARCHAR(255) DEFAULT 'unknown', age INT DEFAULT 18);"],
+            raw_stmt: "INSERT INTO users(age, id) VALUES (30, 1);",
+            prepared: "INSERT INTO users(row_id, id, name, age) VALUES (1, 1, 'unknown', 30);"
+        })
+    }
 }
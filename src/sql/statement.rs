@@ -5,55 +5,106 @@
 
 use std::fmt::{self, Display, Write};
 
+use super::{
+    parser::{
+        BETWEEN_BOUNDS_PRECEDENCE, COMPARISON_PRECEDENCE, UNARY_ARITHMETIC_OPERATOR_PRECEDENCE,
+        UNARY_NOT_OPERATOR_PRECEDENCE,
+    },
+    token::Token,
+    tokenizer::Tokenizer,
+};
+
 /// SQL statement.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Statement {
     Create(Create),
 
-    Select {
-        columns: Vec<Expression>,
-        from: String,
-        r#where: Option<Expression>,
-        order_by: Vec<Expression>,
-    },
+    /// Boxed because it's much bigger than the rest of variants.
+    Select(Box<Select>),
 
     Delete {
-        from: String,
+        from: TableReference,
         r#where: Option<Expression>,
+        returning: Vec<Expression>,
     },
 
     Update {
         table: String,
         columns: Vec<Assignment>,
         r#where: Option<Expression>,
+        returning: Vec<Expression>,
     },
 
     Insert {
         into: String,
         columns: Vec<String>,
-        values: Vec<Expression>,
+        source: InsertSource,
+        returning: Vec<Expression>,
     },
 
     Drop(Drop),
 
+    Truncate(String),
+
+    AlterTable {
+        table: String,
+        action: AlterAction,
+    },
+
+    SetOperation {
+        left: Box<Self>,
+        op: SetOp,
+        all: bool,
+        right: Box<Self>,
+    },
+
     StartTransaction,
 
     Rollback,
 
+    Savepoint(String),
+
+    RollbackTo(String),
+
     Commit,
 
     Explain(Box<Self>),
+
+    /// `SHOW TABLES`.
+    ShowTables,
+
+    /// `SHOW COLUMNS FROM table`.
+    ShowColumns {
+        table: String,
+    },
+
+    /// `DESCRIBE table` or `DESC table`.
+    Describe(String),
+
+    /// `USE database`.
+    Use(String),
 }
 
 /// Expressions used in select, update, delete and insert statements.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Expression {
     Identifier(String),
 
+    /// `table.column`.
+    QualifiedIdentifier {
+        table: String,
+        column: String,
+    },
+
     Value(Value),
 
     Wildcard,
 
+    /// `table.*`.
+    QualifiedWildcard(String),
+
     BinaryOperation {
         left: Box<Self>,
         operator: BinaryOperator,
@@ -66,10 +117,98 @@ pub(crate) enum Expression {
     },
 
     Nested(Box<Self>),
+
+    /// Row value like `(a, b)`. Always has at least two elements, a single
+    /// parenthesized expression is [`Expression::Nested`].
+    Row(Vec<Self>),
+
+    /// Function call like `SUM(price)` or `COUNT(DISTINCT id)`.
+    Function {
+        name: String,
+        /// `DISTINCT` before the arguments, only meaningful for aggregates.
+        distinct: bool,
+        args: Vec<Self>,
+    },
+
+    InList {
+        expr: Box<Self>,
+        list: Vec<Self>,
+        negated: bool,
+    },
+
+    Between {
+        expr: Box<Self>,
+        low: Box<Self>,
+        high: Box<Self>,
+        negated: bool,
+    },
+
+    IsNull {
+        expr: Box<Self>,
+        negated: bool,
+    },
+
+    /// `expr LIKE pattern [ESCAPE 'c']` or its `ILIKE` variant.
+    Like {
+        expr: Box<Self>,
+        pattern: Box<Self>,
+        /// Makes the `%` or `_` that follows it match literally.
+        escape: Option<char>,
+        /// `true` for `ILIKE`, which ignores case when matching.
+        case_insensitive: bool,
+    },
+
+    /// `SELECT` statement used as an expression.
+    Subquery(Box<Statement>),
+
+    Exists {
+        subquery: Box<Statement>,
+        negated: bool,
+    },
+
+    InSubquery {
+        expr: Box<Self>,
+        subquery: Box<Statement>,
+        negated: bool,
+    },
+
+    /// Comparison against all the rows of a subquery like
+    /// `price > ALL (SELECT ...)` or `id = ANY (SELECT ...)`.
+    Quantified {
+        left: Box<Self>,
+        operator: BinaryOperator,
+        quantifier: Quantifier,
+        subquery: Box<Statement>,
+    },
+
+    /// `CASE [operand] WHEN .. THEN .. [ELSE ..] END`.
+    ///
+    /// Without operand each `WHEN` is a boolean condition, with operand each
+    /// `WHEN` is a value compared against the operand.
+    Case {
+        operand: Option<Box<Self>>,
+        when_then: Vec<(Self, Self)>,
+        else_result: Option<Box<Self>>,
+    },
+
+    /// `?` placeholder in prepared statements. Parameters are numbered from
+    /// left to right starting at 1.
+    Parameter(usize),
+
+    /// `:name` or `@name` placeholder in prepared statements. Repeated names
+    /// refer to the same parameter.
+    NamedParameter(String),
+
+    /// `CAST(expr AS data_type)`.
+    Cast {
+        expr: Box<Self>,
+        data_type: DataType,
+    },
 }
 
 /// Binary operators used in expressions.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum BinaryOperator {
     Eq,
     Neq,
@@ -81,37 +220,86 @@ pub(crate) enum BinaryOperator {
     Minus,
     Mul,
     Div,
+    Mod,
     And,
     Or,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+/// `ANY` or `ALL` in [`Expression::Quantified`] comparisons.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Quantifier {
+    Any,
+    All,
 }
 
 /// Unary operators used in expressions.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum UnaryOperator {
     Plus,
     Minus,
+    Not,
 }
 
 /// SQL constraints.
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Constraint {
     PrimaryKey,
     Unique,
+    NotNull,
+    /// Foreign key pointing to the column of another table.
+    References {
+        table: String,
+        column: String,
+    },
+    /// Value used when the column is omitted in `INSERT` statements.
+    Default(Expression),
+    /// Condition that every row must satisfy.
+    Check(Expression),
+    /// Integer column generated automatically when omitted.
+    AutoIncrement,
+}
+
+/// Constraints that apply to multiple columns at the same time. Table level
+/// constraints that only reference one column are stored in
+/// [`Column::constraints`] instead, except for `CHECK`.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum TableConstraint {
+    PrimaryKey(Vec<String>),
+    /// Table level `CHECK (expr)`, which can reference any column.
+    Check(Expression),
 }
 
 /// SQL Data types.
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum DataType {
+    SmallInt,
+    UnsignedSmallInt,
     Int,
     UnsignedInt,
     BigInt,
     UnsignedBigInt,
     Bool,
+    Float,
+    Decimal { precision: u8, scale: u8 },
+    Date,
+    Timestamp,
     Varchar(usize),
+    Text,
 }
 
 /// Resolved values from expressions.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Value {
     /// UTF-8 string.
     String(String),
@@ -134,23 +322,159 @@ pub enum Value {
     /// It's a toy database anyway, not that anyone is gonna run into integer
     /// overflow issues in production :)
     Number(i128),
+
+    /// Double precision floating point number.
+    Float(f64),
+
+    /// SQL `NULL`, absence of value.
+    Null,
+}
+
+/// Body of [`Statement::Select`].
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Select {
+    /// Common table expressions defined in the `WITH` clause.
+    pub ctes: Vec<Cte>,
+    pub distinct: bool,
+    pub columns: Vec<SelectItem>,
+    /// [`None`] for queries like `SELECT 1 + 1` that don't need a table.
+    pub from: Option<TableReference>,
+    pub joins: Vec<Join>,
+    pub r#where: Option<Expression>,
+    pub group_by: Vec<Expression>,
+    pub having: Option<Expression>,
+    pub order_by: Vec<OrderBy>,
+    pub limit: Option<Expression>,
+    pub offset: Option<Expression>,
 }
 
 /// Assignments found in `UPDATE` statements.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Assignment {
     pub identifier: String,
     pub value: Expression,
 }
 
+/// Expression in the column list of `SELECT` statements with its optional
+/// `AS` alias.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct SelectItem {
+    pub expr: Expression,
+    pub alias: Option<String>,
+}
+
+/// Common table expression like `recent AS (SELECT ...)` defined in the
+/// `WITH` clause that precedes `SELECT` statements.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Cte {
+    pub name: String,
+    pub query: Box<Statement>,
+}
+
+/// Sort key in the `ORDER BY` clause of `SELECT` statements.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct OrderBy {
+    pub expr: Expression,
+    pub direction: Direction,
+    /// `NULLS FIRST` or `NULLS LAST`. When omitted [`Value::Null`] is smaller
+    /// than any other value (see [`Value::partial_cmp`]), so nulls go first in
+    /// ascending order and last in descending order.
+    pub nulls: Option<NullsOrder>,
+}
+
+/// `ASC` or `DESC`. Defaults to ascending if not specified.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Direction {
+    Asc,
+    Desc,
+}
+
+/// Position of `NULL` values in sorted results.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum NullsOrder {
+    First,
+    Last,
+}
+
+impl OrderBy {
+    /// Returns `true` if nulls end up at the beginning of the results, either
+    /// because it was explicitly requested or by default.
+    pub fn nulls_first(&self) -> bool {
+        match self.nulls {
+            Some(nulls) => nulls == NullsOrder::First,
+            None => self.direction == Direction::Asc,
+        }
+    }
+}
+
+/// Relation found in the `FROM` clause.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum TableReference {
+    /// Table name with its optional alias. The name can be qualified with
+    /// the database like `shop.products`.
+    Table {
+        database: Option<String>,
+        name: String,
+        alias: Option<String>,
+    },
+    /// Derived table like `(SELECT ...) AS alias`. The alias is mandatory.
+    Subquery {
+        query: Box<Statement>,
+        alias: String,
+    },
+}
+
+/// `JOIN` clause that follows the `FROM` table in `SELECT` statements.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Join {
+    pub join_type: JoinType,
+    pub table: TableReference,
+    pub on: Expression,
+}
+
+/// Kind of `JOIN`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
 /// Column definitions from `INSERT` statements.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Column {
     pub name: String,
     pub data_type: DataType,
     pub constraints: Vec<Constraint>,
 }
 
+impl DataType {
+    /// Returns `true` if this is one of the integer types.
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            Self::SmallInt
+                | Self::UnsignedSmallInt
+                | Self::Int
+                | Self::UnsignedInt
+                | Self::BigInt
+                | Self::UnsignedBigInt
+        )
+    }
+}
+
 impl Column {
     pub fn new(name: &str, data_type: DataType) -> Self {
         Self {
@@ -175,29 +499,293 @@ impl Column {
             constraints: vec![Constraint::Unique],
         }
     }
+
+    /// Returns the `DEFAULT` expression of this column if there is one.
+    pub(crate) fn default_value(&self) -> Option<&Expression> {
+        self.constraints
+            .iter()
+            .find_map(|constraint| match constraint {
+                Constraint::Default(expr) => Some(expr),
+                _ => None,
+            })
+    }
 }
 
 /// `CREATE` statement.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Create {
-    Database(String),
+    Database {
+        name: String,
+        if_not_exists: bool,
+    },
     Table {
         name: String,
         columns: Vec<Column>,
+        table_constraints: Vec<TableConstraint>,
+        if_not_exists: bool,
     },
     Index {
         name: String,
         table: String,
-        column: String,
+        columns: Vec<String>,
         unique: bool,
     },
+    /// `CREATE VIEW name AS SELECT ...`.
+    View {
+        name: String,
+        query: Box<Statement>,
+    },
+}
+
+/// Rows inserted by `INSERT` statements.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum InsertSource {
+    /// `VALUES (1, 2), (3, 4)`.
+    Values(Vec<Vec<Expression>>),
+    /// `INSERT INTO table SELECT ...`.
+    Select(Box<Statement>),
+}
+
+/// Operators that combine the results of two `SELECT` statements.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum SetOp {
+    Union,
+    Intersect,
+    Except,
+}
+
+/// Schema change applied by `ALTER TABLE` statements.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum AlterAction {
+    AddColumn(Column),
+    DropColumn(String),
+    RenameColumn { from: String, to: String },
+    RenameTable(String),
 }
 
 /// `DROP` statement.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Drop {
-    Table(String),
-    Database(String),
+    Table { name: String, if_exists: bool },
+    Database { name: String, if_exists: bool },
+    Index(String),
+    View(String),
+}
+
+/// Displays a statement nested inside another one, which means removing the
+/// terminator.
+fn nested(statement: &Statement) -> String {
+    statement.to_string().trim_end_matches(';').to_owned()
+}
+
+/// Same as [`nested`] but wrapped in parenthesis.
+fn subquery(statement: &Statement) -> String {
+    format!("({})", nested(statement))
+}
+
+impl BinaryOperator {
+    /// Binding power of this operator. The [`super::parser::Parser`] uses it
+    /// to parse infix expressions and [`Display`] to decide where parenthesis
+    /// are needed, so both always agree.
+    pub(super) fn precedence(&self) -> u8 {
+        match self {
+            Self::Or => 5,
+            Self::And => 10,
+            Self::Eq | Self::Neq | Self::Lt | Self::LtEq | Self::Gt | Self::GtEq => {
+                COMPARISON_PRECEDENCE
+            }
+            Self::BitOr => 22,
+            Self::BitXor => 24,
+            Self::BitAnd => 26,
+            Self::ShiftLeft | Self::ShiftRight => 28,
+            Self::Plus | Self::Minus => 30,
+            Self::Mul | Self::Div | Self::Mod => 40,
+        }
+    }
+}
+
+impl Expression {
+    /// Precedence of the outermost operator in this expression. Expressions
+    /// that are delimited on both sides like literals or function calls never
+    /// need parenthesis, so they get the maximum value.
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::BinaryOperation { operator, .. } | Self::Quantified { operator, .. } => {
+                operator.precedence()
+            }
+            Self::UnaryOperation {
+                operator: UnaryOperator::Not,
+                ..
+            } => UNARY_NOT_OPERATOR_PRECEDENCE,
+            Self::UnaryOperation { .. } => UNARY_ARITHMETIC_OPERATOR_PRECEDENCE,
+            Self::InList { .. }
+            | Self::Between { .. }
+            | Self::IsNull { .. }
+            | Self::Like { .. }
+            | Self::InSubquery { .. } => COMPARISON_PRECEDENCE,
+            _ => u8::MAX,
+        }
+    }
+
+    /// Applies `f` to every node of this expression tree bottom-up, so `f`
+    /// always receives nodes whose children have already been transformed.
+    ///
+    /// Statements inside subqueries are not expressions, so `f` won't reach
+    /// the nodes inside them.
+    pub(crate) fn map(self, f: &mut impl FnMut(Self) -> Self) -> Self {
+        let boxed = |expr: Box<Self>, f: &mut _| Box::new(expr.map(f));
+
+        let expr = match self {
+            Self::BinaryOperation {
+                left,
+                operator,
+                right,
+            } => Self::BinaryOperation {
+                left: boxed(left, f),
+                operator,
+                right: boxed(right, f),
+            },
+
+            Self::UnaryOperation { operator, expr } => Self::UnaryOperation {
+                operator,
+                expr: boxed(expr, f),
+            },
+
+            Self::Nested(expr) => Self::Nested(boxed(expr, f)),
+
+            Self::Row(items) => Self::Row(items.into_iter().map(|item| item.map(f)).collect()),
+
+            Self::Function {
+                name,
+                distinct,
+                args,
+            } => Self::Function {
+                name,
+                distinct,
+                args: args.into_iter().map(|arg| arg.map(f)).collect(),
+            },
+
+            Self::InList {
+                expr,
+                list,
+                negated,
+            } => Self::InList {
+                expr: boxed(expr, f),
+                list: list.into_iter().map(|item| item.map(f)).collect(),
+                negated,
+            },
+
+            Self::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => Self::Between {
+                expr: boxed(expr, f),
+                low: boxed(low, f),
+                high: boxed(high, f),
+                negated,
+            },
+
+            Self::IsNull { expr, negated } => Self::IsNull {
+                expr: boxed(expr, f),
+                negated,
+            },
+
+            Self::Like {
+                expr,
+                pattern,
+                escape,
+                case_insensitive,
+            } => Self::Like {
+                expr: boxed(expr, f),
+                pattern: boxed(pattern, f),
+                escape,
+                case_insensitive,
+            },
+
+            Self::InSubquery {
+                expr,
+                subquery,
+                negated,
+            } => Self::InSubquery {
+                expr: boxed(expr, f),
+                subquery,
+                negated,
+            },
+
+            Self::Quantified {
+                left,
+                operator,
+                quantifier,
+                subquery,
+            } => Self::Quantified {
+                left: boxed(left, f),
+                operator,
+                quantifier,
+                subquery,
+            },
+
+            Self::Case {
+                operand,
+                when_then,
+                else_result,
+            } => Self::Case {
+                operand: operand.map(|operand| boxed(operand, f)),
+                when_then: when_then
+                    .into_iter()
+                    .map(|(when, then)| (when.map(f), then.map(f)))
+                    .collect(),
+                else_result: else_result.map(|else_result| boxed(else_result, f)),
+            },
+
+            Self::Cast { expr, data_type } => Self::Cast {
+                expr: boxed(expr, f),
+                data_type,
+            },
+
+            leaf @ (Self::Identifier(_)
+            | Self::QualifiedIdentifier { .. }
+            | Self::Value(_)
+            | Self::Wildcard
+            | Self::QualifiedWildcard(_)
+            | Self::Subquery(_)
+            | Self::Exists { .. }
+            | Self::Parameter(_)
+            | Self::NamedParameter(_)) => leaf,
+        };
+
+        f(expr)
+    }
+}
+
+/// Displays the operand of an operator with the given `precedence`, wrapping it
+/// in parenthesis if the parser would otherwise build a different tree.
+///
+/// Operators are left associative, so operands on the right side need
+/// parenthesis when they have the same precedence as well: `a - (b - c)`.
+fn operand(expr: &Expression, precedence: u8, right: bool) -> String {
+    let expr_precedence = expr.precedence();
+
+    if expr_precedence < precedence || (right && expr_precedence == precedence) {
+        format!("({expr})")
+    } else {
+        expr.to_string()
+    }
+}
+
+/// Wraps `ident` in backticks if it would otherwise be tokenized as a keyword,
+/// so that displayed statements can be parsed again.
+fn quote_identifier(ident: &str) -> String {
+    match Tokenizer::new(ident).tokenize().as_deref() {
+        Ok([Token::Identifier(_), Token::Eof]) => ident.to_owned(),
+        _ => format!("`{ident}`"),
+    }
 }
 
 /// Optimized version of [`std::slice::Join`] with no intermediary [`Vec`] and
@@ -229,14 +817,26 @@ impl PartialOrd for Value {
     ///
     /// The codebases uses the [`None`] value as a "type error" when comparing
     /// values, but type errors should never happen because the
-    /// [`super::analyzer`] must catch all of them. If we add a float type
-    /// (which does not form a total order) then we should add a custom
-    /// `try_partial_cmp` method to values in order to avoid confusion.
+    /// [`super::analyzer`] must catch all of them. Integers and floats are
+    /// different types too, comparing them numerically would be inconsistent
+    /// with the derived [`PartialEq`] since `2` and `2.0` are not equal.
+    ///
+    /// The exception is [`Value::Null`], which is smaller than any other value
+    /// and equal to itself, so `ORDER BY` puts nulls first like SQLite does.
+    /// Whether a comparison involving `NULL` makes sense in an expression is
+    /// up to the caller.
+    ///
+    /// Floats don't form a total order because of NaN, so there's no [`Ord`]
+    /// impl for values.
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         match (self, other) {
             (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+            (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
             (Value::String(a), Value::String(b)) => a.partial_cmp(b),
             (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
+            (Value::Null, Value::Null) => Some(std::cmp::Ordering::Equal),
+            (Value::Null, _) => Some(std::cmp::Ordering::Less),
+            (_, Value::Null) => Some(std::cmp::Ordering::Greater),
             _ => None,
         }
     }
@@ -246,8 +846,10 @@ impl Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Value::Number(number) => write!(f, "{number}"),
-            Value::String(string) => write!(f, "\"{string}\""),
+            Value::Float(float) => write!(f, "{float:?}"),
+            Value::String(string) => write!(f, "\"{}\"", string.replace('"', "\"\"")),
             Value::Bool(bool) => f.write_str(if *bool { "TRUE" } else { "FALSE" }),
+            Value::Null => f.write_str("NULL"),
         }
     }
 }
@@ -255,26 +857,61 @@ impl Display for Value {
 impl Display for DataType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            DataType::SmallInt => f.write_str("SMALLINT"),
+            DataType::UnsignedSmallInt => f.write_str("SMALLINT UNSIGNED"),
             DataType::Int => f.write_str("INT"),
             DataType::UnsignedInt => f.write_str("INT UNSIGNED"),
             DataType::BigInt => f.write_str("BIGINT"),
             DataType::UnsignedBigInt => f.write_str("BIGINT UNSIGNED"),
             DataType::Bool => f.write_str("BOOL"),
+            DataType::Float => f.write_str("FLOAT"),
+            DataType::Decimal { precision, scale } => write!(f, "DECIMAL({precision},{scale})"),
+            DataType::Date => f.write_str("DATE"),
+            DataType::Timestamp => f.write_str("TIMESTAMP"),
             DataType::Varchar(max) => write!(f, "VARCHAR({max})"),
+            DataType::Text => f.write_str("TEXT"),
+        }
+    }
+}
+
+impl TableConstraint {
+    /// Takes the expressions of all the [`TableConstraint::Check`] variants.
+    pub fn checks(constraints: Vec<Self>) -> Vec<Expression> {
+        constraints
+            .into_iter()
+            .filter_map(|constraint| match constraint {
+                Self::Check(expr) => Some(expr),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::PrimaryKey(columns) => write!(f, "PRIMARY KEY ({})", columns.join(", ")),
+            Self::Check(expr) => write!(f, "CHECK ({expr})"),
         }
     }
 }
 
 impl Display for Column {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}", self.name, self.data_type)?;
+        write!(f, "{} {}", quote_identifier(&self.name), self.data_type)?;
 
         for constraint in &self.constraints {
-            f.write_char(' ')?;
-            f.write_str(match constraint {
-                Constraint::PrimaryKey => "PRIMARY KEY",
-                Constraint::Unique => "UNIQUE",
-            })?;
+            match constraint {
+                Constraint::PrimaryKey => f.write_str(" PRIMARY KEY")?,
+                Constraint::Unique => f.write_str(" UNIQUE")?,
+                Constraint::NotNull => f.write_str(" NOT NULL")?,
+                Constraint::References { table, column } => {
+                    write!(f, " REFERENCES {table}({column})")?
+                }
+                Constraint::Default(expr) => write!(f, " DEFAULT {expr}")?,
+                Constraint::Check(expr) => write!(f, " CHECK ({expr})")?,
+                Constraint::AutoIncrement => f.write_str(" AUTO_INCREMENT")?,
+            }
         }
 
         Ok(())
@@ -287,6 +924,119 @@ impl Display for Assignment {
     }
 }
 
+impl From<Expression> for SelectItem {
+    fn from(expr: Expression) -> Self {
+        Self { expr, alias: None }
+    }
+}
+
+impl Display for SelectItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)?;
+
+        if let Some(alias) = &self.alias {
+            write!(f, " AS {alias}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Expression> for OrderBy {
+    fn from(expr: Expression) -> Self {
+        Self {
+            expr,
+            direction: Direction::Asc,
+            nulls: None,
+        }
+    }
+}
+
+impl Display for Cte {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} AS {}", self.name, subquery(&self.query))
+    }
+}
+
+impl Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)?;
+
+        if self.direction == Direction::Desc {
+            f.write_str(" DESC")?;
+        }
+
+        match self.nulls {
+            Some(NullsOrder::First) => f.write_str(" NULLS FIRST"),
+            Some(NullsOrder::Last) => f.write_str(" NULLS LAST"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl From<&str> for TableReference {
+    fn from(name: &str) -> Self {
+        Self::Table {
+            database: None,
+            name: name.into(),
+            alias: None,
+        }
+    }
+}
+
+impl Display for TableReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Table {
+                database,
+                name,
+                alias,
+            } => {
+                if let Some(database) = database {
+                    write!(f, "{database}.")?;
+                }
+
+                f.write_str(name)?;
+
+                if let Some(alias) = alias {
+                    write!(f, " AS {alias}")?;
+                }
+
+                Ok(())
+            }
+
+            Self::Subquery { query, alias } => write!(f, "{} AS {alias}", subquery(query)),
+        }
+    }
+}
+
+impl Display for Join {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} JOIN {} ON {}", self.join_type, self.table, self.on)
+    }
+}
+
+impl Display for JoinType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Inner => "INNER",
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+            Self::Full => "FULL",
+        })
+    }
+}
+
+impl Display for SetOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Union => "UNION",
+            Self::Intersect => "INTERSECT",
+            Self::Except => "EXCEPT",
+        })
+    }
+}
+
 impl Display for BinaryOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(match self {
@@ -300,17 +1050,33 @@ impl Display for BinaryOperator {
             BinaryOperator::Minus => "-",
             BinaryOperator::Mul => "*",
             BinaryOperator::Div => "/",
+            BinaryOperator::Mod => "%",
             BinaryOperator::And => "AND",
             BinaryOperator::Or => "OR",
+            BinaryOperator::BitAnd => "&",
+            BinaryOperator::BitOr => "|",
+            BinaryOperator::BitXor => "^",
+            BinaryOperator::ShiftLeft => "<<",
+            BinaryOperator::ShiftRight => ">>",
+        })
+    }
+}
+
+impl Display for Quantifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Quantifier::Any => "ANY",
+            Quantifier::All => "ALL",
         })
     }
 }
 
 impl Display for UnaryOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_char(match self {
-            UnaryOperator::Minus => '-',
-            UnaryOperator::Plus => '+',
+        f.write_str(match self {
+            UnaryOperator::Minus => "-",
+            UnaryOperator::Plus => "+",
+            UnaryOperator::Not => "NOT",
         })
     }
 }
@@ -318,20 +1084,131 @@ impl Display for UnaryOperator {
 impl Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::Identifier(ident) => f.write_str(ident),
+            Self::Identifier(ident) => f.write_str(&quote_identifier(ident)),
+            Self::QualifiedIdentifier { table, column } => write!(f, "{table}.{column}"),
             Self::Value(value) => write!(f, "{value}"),
             Self::Wildcard => f.write_char('*'),
+            Self::QualifiedWildcard(table) => write!(f, "{table}.*"),
             Self::BinaryOperation {
                 left,
                 operator,
                 right,
             } => {
+                let precedence = operator.precedence();
+                let left = operand(left, precedence, false);
+                let right = operand(right, precedence, true);
                 write!(f, "{left} {operator} {right}")
             }
             Self::UnaryOperation { operator, expr } => {
-                write!(f, "{operator}{expr}")
+                let mut expr = operand(expr, self.precedence(), false);
+                match operator {
+                    UnaryOperator::Not => write!(f, "{operator} {expr}"),
+                    _ => {
+                        // Avoid "--" which starts a comment.
+                        if expr.starts_with(['-', '+']) {
+                            expr = format!("({expr})");
+                        }
+                        write!(f, "{operator}{expr}")
+                    }
+                }
             }
             Self::Nested(expr) => write!(f, "({expr})"),
+            Self::Row(items) => write!(f, "({})", join(items, ", ")),
+            Self::Function {
+                name,
+                distinct,
+                args,
+            } => {
+                let distinct = if *distinct { "DISTINCT " } else { "" };
+                write!(f, "{name}({distinct}{})", join(args, ", "))
+            }
+            Self::InList {
+                expr,
+                list,
+                negated,
+            } => {
+                let not = if *negated { "NOT " } else { "" };
+                let expr = operand(expr, COMPARISON_PRECEDENCE, false);
+                write!(f, "{expr} {not}IN ({})", join(list, ", "))
+            }
+            Self::Between {
+                expr,
+                low,
+                high,
+                negated,
+            } => {
+                let not = if *negated { "NOT " } else { "" };
+                let expr = operand(expr, COMPARISON_PRECEDENCE, false);
+                let low = operand(low, BETWEEN_BOUNDS_PRECEDENCE, true);
+                let high = operand(high, BETWEEN_BOUNDS_PRECEDENCE, true);
+                write!(f, "{expr} {not}BETWEEN {low} AND {high}")
+            }
+            Self::IsNull { expr, negated } => {
+                let not = if *negated { " NOT" } else { "" };
+                let expr = operand(expr, COMPARISON_PRECEDENCE, false);
+                write!(f, "{expr} IS{not} NULL")
+            }
+            Self::Like {
+                expr,
+                pattern,
+                escape,
+                case_insensitive,
+            } => {
+                let like = if *case_insensitive { "ILIKE" } else { "LIKE" };
+                let expr = operand(expr, COMPARISON_PRECEDENCE, false);
+                let pattern = operand(pattern, COMPARISON_PRECEDENCE, true);
+                write!(f, "{expr} {like} {pattern}")?;
+                if let Some(escape) = escape {
+                    write!(f, " ESCAPE {}", Value::String(escape.to_string()))?;
+                }
+                Ok(())
+            }
+            Self::Subquery(statement) => f.write_str(&subquery(statement)),
+            Self::Exists {
+                subquery: statement,
+                negated,
+            } => {
+                let not = if *negated { "NOT " } else { "" };
+                write!(f, "{not}EXISTS {}", subquery(statement))
+            }
+            Self::InSubquery {
+                expr,
+                subquery: statement,
+                negated,
+            } => {
+                let not = if *negated { " NOT" } else { "" };
+                let expr = operand(expr, COMPARISON_PRECEDENCE, false);
+                write!(f, "{expr}{not} IN {}", subquery(statement))
+            }
+            Self::Quantified {
+                left,
+                operator,
+                quantifier,
+                subquery: statement,
+            } => {
+                let left = operand(left, operator.precedence(), false);
+                write!(f, "{left} {operator} {quantifier} {}", subquery(statement))
+            }
+            Self::Case {
+                operand,
+                when_then,
+                else_result,
+            } => {
+                f.write_str("CASE")?;
+                if let Some(operand) = operand {
+                    write!(f, " {operand}")?;
+                }
+                for (condition, result) in when_then {
+                    write!(f, " WHEN {condition} THEN {result}")?;
+                }
+                if let Some(else_result) = else_result {
+                    write!(f, " ELSE {else_result}")?;
+                }
+                f.write_str(" END")
+            }
+            Self::Parameter(_) => f.write_char('?'),
+            Self::NamedParameter(name) => write!(f, ":{name}"),
+            Self::Cast { expr, data_type } => write!(f, "CAST({expr} AS {data_type})"),
         }
     }
 }
@@ -340,62 +1217,129 @@ impl Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Statement::Create(create) => match create {
-                Create::Table { name, columns } => {
-                    write!(f, "CREATE TABLE {name} ({})", join(columns, ", "))?;
+                Create::Table {
+                    name,
+                    columns,
+                    table_constraints,
+                    if_not_exists,
+                } => {
+                    let if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" };
+                    write!(
+                        f,
+                        "CREATE TABLE {if_not_exists}{name} ({}",
+                        join(columns, ", ")
+                    )?;
+                    for constraint in table_constraints {
+                        write!(f, ", {constraint}")?;
+                    }
+                    f.write_char(')')?;
                 }
 
-                Create::Database(name) => {
-                    write!(f, "CREATE DATABASE {name}")?;
+                Create::Database {
+                    name,
+                    if_not_exists,
+                } => {
+                    let if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" };
+                    write!(f, "CREATE DATABASE {if_not_exists}{name}")?;
                 }
 
                 Create::Index {
                     name,
                     table,
-                    column,
+                    columns,
                     unique,
                 } => {
                     let unique = if *unique { " UNIQUE " } else { " " };
-                    write!(f, "CREATE{unique}INDEX {name} ON {table}({column})")?;
+                    let columns = columns.iter().map(|col| quote_identifier(col));
+                    let columns = columns.collect::<Vec<_>>().join(", ");
+                    write!(f, "CREATE{unique}INDEX {name} ON {table}({columns})")?;
+                }
+
+                Create::View { name, query } => {
+                    write!(f, "CREATE VIEW {name} AS {}", nested(query))?;
                 }
             },
 
-            Statement::Select {
-                columns,
-                from,
-                r#where,
-                order_by,
-            } => {
-                write!(f, "SELECT {} FROM {from}", join(columns, ", "))?;
+            Statement::Select(select) => {
+                let Select {
+                    ctes,
+                    distinct,
+                    columns,
+                    from,
+                    joins,
+                    r#where,
+                    group_by,
+                    having,
+                    order_by,
+                    limit,
+                    offset,
+                } = &**select;
+
+                if !ctes.is_empty() {
+                    write!(f, "WITH {} ", join(ctes, ", "))?;
+                }
+                let distinct = if *distinct { "DISTINCT " } else { "" };
+                write!(f, "SELECT {distinct}{}", join(columns, ", "))?;
+                if let Some(from) = from {
+                    write!(f, " FROM {from}")?;
+                }
+                for join in joins {
+                    write!(f, " {join}")?;
+                }
                 if let Some(expr) = r#where {
                     write!(f, " WHERE {expr}")?;
                 }
+                if !group_by.is_empty() {
+                    write!(f, " GROUP BY {}", join(group_by, ", "))?;
+                }
+                if let Some(expr) = having {
+                    write!(f, " HAVING {expr}")?;
+                }
                 if !order_by.is_empty() {
                     write!(f, " ORDER BY {}", join(order_by, ", "))?;
                 }
+                if let Some(expr) = limit {
+                    write!(f, " LIMIT {expr}")?;
+                }
+                if let Some(expr) = offset {
+                    write!(f, " OFFSET {expr}")?;
+                }
             }
 
-            Statement::Delete { from, r#where } => {
+            Statement::Delete {
+                from,
+                r#where,
+                returning,
+            } => {
                 write!(f, "DELETE FROM {from}")?;
                 if let Some(expr) = r#where {
                     write!(f, " WHERE {expr}")?;
                 }
+                if !returning.is_empty() {
+                    write!(f, " RETURNING {}", join(returning, ", "))?;
+                }
             }
 
             Statement::Update {
                 table,
                 columns,
                 r#where,
+                returning,
             } => {
                 write!(f, "UPDATE {table} SET {}", join(columns, ", "))?;
                 if let Some(expr) = r#where {
                     write!(f, " WHERE {expr}")?;
                 }
+                if !returning.is_empty() {
+                    write!(f, " RETURNING {}", join(returning, ", "))?;
+                }
             }
 
             Statement::Insert {
                 into,
                 columns,
-                values,
+                source,
+                returning,
             } => {
                 let columns = if columns.is_empty() {
                     String::from(" ")
@@ -403,20 +1347,62 @@ impl Display for Statement {
                     format!(" ({}) ", join(columns, ", "))
                 };
 
-                write!(
-                    f,
-                    "INSERT INTO {into}{columns}VALUES ({})",
-                    join(values, ", ")
-                )?;
+                write!(f, "INSERT INTO {into}{columns}")?;
+
+                match source {
+                    InsertSource::Values(rows) => {
+                        let rows = rows
+                            .iter()
+                            .map(|values| format!("({})", join(values, ", ")));
+                        write!(f, "VALUES {}", rows.collect::<Vec<_>>().join(", "))?;
+                    }
+                    InsertSource::Select(select) => f.write_str(&nested(select))?,
+                }
+
+                if !returning.is_empty() {
+                    write!(f, " RETURNING {}", join(returning, ", "))?;
+                }
             }
 
             Statement::Drop(drop) => {
                 match drop {
-                    Drop::Table(name) => write!(f, "DROP TABLE {name}")?,
-                    Drop::Database(name) => write!(f, "DROP DATABASE {name}")?,
+                    Drop::Table { name, if_exists } => {
+                        let if_exists = if *if_exists { "IF EXISTS " } else { "" };
+                        write!(f, "DROP TABLE {if_exists}{name}")?
+                    }
+                    Drop::Database { name, if_exists } => {
+                        let if_exists = if *if_exists { "IF EXISTS " } else { "" };
+                        write!(f, "DROP DATABASE {if_exists}{name}")?
+                    }
+                    Drop::Index(name) => write!(f, "DROP INDEX {name}")?,
+                    Drop::View(name) => write!(f, "DROP VIEW {name}")?,
                 };
             }
 
+            Statement::AlterTable { table, action } => {
+                write!(f, "ALTER TABLE {table} ")?;
+                match action {
+                    AlterAction::AddColumn(column) => write!(f, "ADD COLUMN {column}")?,
+                    AlterAction::DropColumn(column) => write!(f, "DROP COLUMN {column}")?,
+                    AlterAction::RenameColumn { from, to } => {
+                        write!(f, "RENAME COLUMN {from} TO {to}")?
+                    }
+                    AlterAction::RenameTable(name) => write!(f, "RENAME TO {name}")?,
+                };
+            }
+
+            Statement::Truncate(table) => write!(f, "TRUNCATE TABLE {table}")?,
+
+            Statement::SetOperation {
+                left,
+                op,
+                all,
+                right,
+            } => {
+                let all = if *all { " ALL" } else { "" };
+                write!(f, "{} {op}{all} {}", nested(left), nested(right))?;
+            }
+
             Statement::StartTransaction => {
                 f.write_str("START TRANSACTION")?;
             }
@@ -429,9 +1415,175 @@ impl Display for Statement {
                 f.write_str("ROLLBACK")?;
             }
 
+            Statement::Savepoint(name) => write!(f, "SAVEPOINT {name}")?,
+
+            Statement::RollbackTo(name) => write!(f, "ROLLBACK TO SAVEPOINT {name}")?,
+
             Statement::Explain(statement) => write!(f, "EXPLAIN {statement}")?,
+
+            Statement::ShowTables => f.write_str("SHOW TABLES")?,
+
+            Statement::ShowColumns { table } => write!(f, "SHOW COLUMNS FROM {table}")?,
+
+            Statement::Describe(table) => write!(f, "DESCRIBE {table}")?,
+
+            Statement::Use(database) => write!(f, "USE {database}")?,
         };
 
         f.write_char(';')
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use super::{BinaryOperator, Expression, Statement, Value};
+    use crate::sql::parser::{Parser, ParserError};
+
+    #[test]
+    fn number_ordering() {
+        assert!(Value::Number(-5) < Value::Number(3));
+        assert!(Value::Number(10) > Value::Number(9));
+        assert!(Value::Float(1.5) < Value::Float(2.25));
+        assert_eq!(Value::Number(2).partial_cmp(&Value::Float(2.5)), None);
+        assert_eq!(Value::Float(2.0).partial_cmp(&Value::Number(2)), None);
+        assert_ne!(Value::Number(2), Value::Float(2.0));
+        assert_eq!(Value::Float(f64::NAN).partial_cmp(&Value::Float(1.0)), None);
+    }
+
+    #[test]
+    fn string_ordering() {
+        assert!(Value::String("abc".into()) < Value::String("abd".into()));
+        assert!(Value::String("ab".into()) < Value::String("abc".into()));
+        assert!(Value::String("B".into()) < Value::String("a".into()));
+    }
+
+    #[test]
+    fn nulls_sort_first() {
+        assert_eq!(Value::Null.partial_cmp(&Value::Null), Some(Ordering::Equal));
+        assert!(Value::Null < Value::Number(i128::MIN));
+        assert!(Value::Null < Value::String("".into()));
+        assert!(Value::Bool(false) > Value::Null);
+    }
+
+    #[test]
+    fn mixed_types_are_not_comparable() {
+        assert_eq!(
+            Value::Number(1).partial_cmp(&Value::String("1".into())),
+            None
+        );
+        assert_eq!(Value::Bool(true).partial_cmp(&Value::Number(1)), None);
+        assert_eq!(
+            Value::String("1".into()).partial_cmp(&Value::Float(1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn map_identifiers_to_constant() -> Result<(), ParserError> {
+        let expr = Parser::new("x + 1 > y AND (x * 2 BETWEEN 0 AND MAX(x, z) OR x IN (x, 3))")
+            .parse_expression()?;
+
+        let mapped = expr.map(&mut |expr| match expr {
+            Expression::Identifier(ident) if ident == "x" => Expression::Value(Value::Number(5)),
+            other => other,
+        });
+
+        assert_eq!(
+            mapped,
+            Parser::new("5 + 1 > y AND (5 * 2 BETWEEN 0 AND MAX(5, z) OR 5 IN (5, 3))")
+                .parse_expression()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn map_bottom_up() -> Result<(), ParserError> {
+        let expr = Parser::new("(1 + 2) * x").parse_expression()?;
+        let mut visited = Vec::new();
+
+        let mapped = expr.map(&mut |expr| {
+            visited.push(expr.to_string());
+            match expr {
+                Expression::BinaryOperation {
+                    left,
+                    operator: BinaryOperator::Plus,
+                    right,
+                } if matches!(
+                    (&*left, &*right),
+                    (Expression::Value(_), Expression::Value(_))
+                ) =>
+                {
+                    Expression::Value(Value::Number(3))
+                }
+                other => other,
+            }
+        });
+
+        assert_eq!(visited, ["1", "2", "1 + 2", "(3)", "x", "(3) * x"]);
+        assert_eq!(mapped, Parser::new("(3) * x").parse_expression()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn clone_is_deep() -> Result<(), ParserError> {
+        let original = Parser::new("SELECT a + (b * 2) FROM t WHERE c IN (SELECT d FROM u);")
+            .parse_statement()?;
+        let mut cloned = original.clone();
+
+        let Statement::Select(select) = &mut cloned else {
+            unreachable!();
+        };
+
+        select.columns[0].expr = select.columns[0].expr.clone().map(&mut |expr| match expr {
+            Expression::Identifier(ident) if ident == "b" => Expression::Value(Value::Number(3)),
+            other => other,
+        });
+        select.r#where = None;
+
+        assert_eq!(
+            original,
+            Parser::new("SELECT a + (b * 2) FROM t WHERE c IN (SELECT d FROM u);")
+                .parse_statement()?
+        );
+        assert_eq!(
+            cloned,
+            Parser::new("SELECT a + (3 * 2) FROM t;").parse_statement()?
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trip() -> Result<(), ParserError> {
+        let statement = Parser::new(
+            "SELECT DISTINCT u.id AS user, COUNT(*), -1.5, NULL FROM users u LEFT JOIN orders o ON u.id = o.user_id WHERE u.name LIKE 'J%' AND u.age BETWEEN 18 AND 65 AND o.id IN (SELECT id FROM items) GROUP BY u.id HAVING COUNT(*) > 1 ORDER BY u.id LIMIT 10 OFFSET 5;",
+        )
+        .parse_statement()?;
+
+        let json = serde_json::to_string(&statement).unwrap();
+
+        assert_eq!(serde_json::from_str::<Statement>(&json).unwrap(), statement);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trip_create_table() -> Result<(), ParserError> {
+        let statement = Parser::new(
+            "CREATE TABLE products (id INT UNSIGNED PRIMARY KEY, name VARCHAR(255) NOT NULL DEFAULT 'none', price DECIMAL(10,2) CHECK (price > 0));",
+        )
+        .parse_statement()?;
+
+        let json = serde_json::to_string(&statement).unwrap();
+
+        assert_eq!(serde_json::from_str::<Statement>(&json).unwrap(), statement);
+
+        Ok(())
+    }
+}
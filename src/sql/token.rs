@@ -18,12 +18,23 @@ pub(crate) enum Token {
     GtEq,
     Mul,
     Div,
+    Percent,
     Plus,
     Minus,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
     LeftParen,
     RightParen,
     Comma,
+    Dot,
     SemiColon,
+    /// `?` parameter in prepared statements.
+    Placeholder,
+    /// `:name` or `@name` parameter in prepared statements, without prefix.
+    NamedPlaceholder(String),
     /// Not a real token, used to mark the end of a token stream.
     Eof,
 }
@@ -49,6 +60,8 @@ pub(crate) enum Keyword {
     Unique,
     Table,
     Database,
+    View,
+    SmallInt,
     Int,
     BigInt,
     Unsigned,
@@ -65,16 +78,89 @@ pub(crate) enum Keyword {
     Rollback,
     Commit,
     Explain,
+    Limit,
+    Offset,
+    Group,
+    Having,
+    Distinct,
+    Like,
+    In,
+    Not,
+    Between,
+    Is,
+    Null,
+    Float,
+    Real,
+    Double,
+    Decimal,
+    Numeric,
+    Date,
+    Timestamp,
+    Text,
+    Default,
+    Foreign,
+    References,
+    Check,
+    AutoIncrement,
+    Alter,
+    Add,
+    Column,
+    Rename,
+    To,
+    If,
+    Exists,
+    As,
+    Join,
+    Inner,
+    Left,
+    Right,
+    Full,
+    Outer,
+    Union,
+    Intersect,
+    Except,
+    All,
+    Returning,
+    Truncate,
+    Begin,
+    Savepoint,
+    Case,
+    When,
+    Then,
+    Else,
+    End,
+    Cast,
+    Show,
+    Tables,
+    Columns,
+    Describe,
+    Desc,
+    Asc,
+    Nulls,
+    First,
+    Last,
+    Use,
+    With,
+    Any,
+    Escape,
+    ILike,
     /// Not a keyword, used for convenience. See [`super::tokenizer::Tokenizer`].
     None,
 }
 
 /// Separators between keywords, identifiers, operators, etc.
+///
+/// Comments are treated as whitespace since they can appear anywhere and the
+/// parser doesn't care about them.
 #[derive(PartialEq, Debug)]
 pub(crate) enum Whitespace {
     Space,
     Tab,
     Newline,
+    /// `-- comment` without the leading `--` and the trailing newline.
+    SingleLineComment(String),
+    /// `/* comment */` without the delimiters. Can span multiple lines.
+    MultiLineComment(String),
 }
 
 impl Keyword {
@@ -121,12 +207,21 @@ impl Display for Token {
             Self::GtEq => f.write_str(">="),
             Self::Mul => f.write_str("*"),
             Self::Div => f.write_str("/"),
+            Self::Percent => f.write_str("%"),
             Self::Plus => f.write_str("+"),
             Self::Minus => f.write_str("-"),
+            Self::BitAnd => f.write_str("&"),
+            Self::BitOr => f.write_str("|"),
+            Self::BitXor => f.write_str("^"),
+            Self::ShiftLeft => f.write_str("<<"),
+            Self::ShiftRight => f.write_str(">>"),
             Self::LeftParen => f.write_str("("),
             Self::RightParen => f.write_str(")"),
             Self::Comma => f.write_str(","),
+            Self::Dot => f.write_str("."),
             Self::SemiColon => f.write_str(";"),
+            Self::Placeholder => f.write_str("?"),
+            Self::NamedPlaceholder(name) => write!(f, ":{name}"),
         }
     }
 }
@@ -152,6 +247,8 @@ impl Display for Keyword {
             Self::Unique => "UNIQUE",
             Self::Table => "TABLE",
             Self::Database => "DATABASE",
+            Self::View => "VIEW",
+            Self::SmallInt => "SMALLINT",
             Self::Int => "INT",
             Self::BigInt => "BIGINT",
             Self::Unsigned => "UNSIGNED",
@@ -163,11 +260,77 @@ impl Display for Keyword {
             Self::By => "BY",
             Self::Index => "INDEX",
             Self::On => "ON",
-            Self::Start => "BEGIN",
+            Self::Start => "START",
             Self::Transaction => "TRANSACTION",
             Self::Rollback => "ROLLBACK",
             Self::Commit => "COMMIT",
             Self::Explain => "EXPLAIN",
+            Self::Limit => "LIMIT",
+            Self::Offset => "OFFSET",
+            Self::Group => "GROUP",
+            Self::Having => "HAVING",
+            Self::Distinct => "DISTINCT",
+            Self::Like => "LIKE",
+            Self::In => "IN",
+            Self::Not => "NOT",
+            Self::Between => "BETWEEN",
+            Self::Is => "IS",
+            Self::Null => "NULL",
+            Self::Float => "FLOAT",
+            Self::Real => "REAL",
+            Self::Double => "DOUBLE",
+            Self::Decimal => "DECIMAL",
+            Self::Numeric => "NUMERIC",
+            Self::Date => "DATE",
+            Self::Timestamp => "TIMESTAMP",
+            Self::Text => "TEXT",
+            Self::Default => "DEFAULT",
+            Self::Foreign => "FOREIGN",
+            Self::References => "REFERENCES",
+            Self::Check => "CHECK",
+            Self::AutoIncrement => "AUTO_INCREMENT",
+            Self::Alter => "ALTER",
+            Self::Add => "ADD",
+            Self::Column => "COLUMN",
+            Self::Rename => "RENAME",
+            Self::To => "TO",
+            Self::If => "IF",
+            Self::Exists => "EXISTS",
+            Self::As => "AS",
+            Self::Join => "JOIN",
+            Self::Inner => "INNER",
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+            Self::Full => "FULL",
+            Self::Outer => "OUTER",
+            Self::Union => "UNION",
+            Self::Intersect => "INTERSECT",
+            Self::Except => "EXCEPT",
+            Self::All => "ALL",
+            Self::Returning => "RETURNING",
+            Self::Truncate => "TRUNCATE",
+            Self::Begin => "BEGIN",
+            Self::Savepoint => "SAVEPOINT",
+            Self::Case => "CASE",
+            Self::When => "WHEN",
+            Self::Then => "THEN",
+            Self::Else => "ELSE",
+            Self::End => "END",
+            Self::Cast => "CAST",
+            Self::Show => "SHOW",
+            Self::Tables => "TABLES",
+            Self::Columns => "COLUMNS",
+            Self::Describe => "DESCRIBE",
+            Self::Desc => "DESC",
+            Self::Asc => "ASC",
+            Self::Nulls => "NULLS",
+            Self::First => "FIRST",
+            Self::Last => "LAST",
+            Self::Use => "USE",
+            Self::With => "WITH",
+            Self::Any => "ANY",
+            Self::Escape => "ESCAPE",
+            Self::ILike => "ILIKE",
             Self::None => "_",
         })
     }
@@ -175,10 +338,12 @@ impl Display for Keyword {
 
 impl Display for Whitespace {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_char(match self {
-            Self::Space => ' ',
-            Self::Tab => '\t',
-            Self::Newline => '\n',
-        })
+        match self {
+            Self::Space => f.write_char(' '),
+            Self::Tab => f.write_char('\t'),
+            Self::Newline => f.write_char('\n'),
+            Self::SingleLineComment(comment) => write!(f, "--{comment}"),
+            Self::MultiLineComment(comment) => write!(f, "/*{comment}*/"),
+        }
     }
 }
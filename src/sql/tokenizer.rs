@@ -1,6 +1,6 @@
 //! SQL tokenizer that produces [`Token`] instances.
 
-use std::{fmt::Display, iter::Peekable, str::Chars};
+use std::{error::Error, fmt::Display, iter::Peekable, str::Chars};
 
 use super::token::{Keyword, Token, Whitespace};
 
@@ -19,11 +19,14 @@ impl Default for Location {
     }
 }
 
-/// Stores both the [`Token`] and its starting location in the input string.
+/// Stores both the [`Token`] and the span it covers in the input string.
 #[derive(Debug, PartialEq)]
 pub(super) struct TokenWithLocation {
     pub variant: Token,
+    /// Location of the first character of the token.
     pub location: Location,
+    /// Location right after the last character of the token.
+    pub end: Location,
 }
 
 impl TokenWithLocation {
@@ -132,6 +135,10 @@ pub(crate) enum ErrorKind {
 
     StringNotClosed,
 
+    CommentNotClosed,
+
+    IdentifierNotClosed,
+
     Other(String),
 }
 
@@ -152,6 +159,10 @@ impl Display for ErrorKind {
 
             ErrorKind::StringNotClosed => f.write_str("string not closed"),
 
+            ErrorKind::CommentNotClosed => f.write_str("comment not closed"),
+
+            ErrorKind::IdentifierNotClosed => f.write_str("quoted identifier not closed"),
+
             ErrorKind::OperatorNotClosed(operator) => write!(f, "'{operator}' operator not closed"),
 
             ErrorKind::Other(message) => f.write_str(message),
@@ -159,6 +170,8 @@ impl Display for ErrorKind {
     }
 }
 
+impl Error for ErrorKind {}
+
 /// If the tokenizer finds an error it means to syntax is not correct.
 ///
 /// Some examples are unclosed strings, unclosed operators, etc.
@@ -169,6 +182,18 @@ pub(super) struct TokenizerError {
     pub input: String,
 }
 
+impl Display for TokenizerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Tokenizer Error at line {} column {}: {}",
+            self.location.line, self.location.col, self.kind
+        )
+    }
+}
+
+impl Error for TokenizerError {}
+
 /// Main parsing structure. See [`Tokenizer::next_token`].
 pub(super) struct Tokenizer<'i> {
     /// Character stream.
@@ -224,14 +249,15 @@ impl<'i> Tokenizer<'i> {
         }
     }
 
-    /// Same as [`Self::next_token`] but returns the starting location
-    /// of the token as well.
+    /// Same as [`Self::next_token`] but returns the starting and ending
+    /// locations of the token as well.
     fn next_token_with_location(&mut self) -> Result<TokenWithLocation, TokenizerError> {
         let location = self.stream.location();
 
         self.next_token().map(|token| TokenWithLocation {
             variant: token,
             location,
+            end: self.stream.location(),
         })
     }
 
@@ -256,22 +282,45 @@ impl<'i> Tokenizer<'i> {
             },
 
             '<' => match self.stream.peek_next() {
+                Some('<') => self.consume(Token::ShiftLeft),
                 Some('=') => self.consume(Token::LtEq),
+                Some('>') => self.consume(Token::Neq),
                 _ => Ok(Token::Lt),
             },
 
             '>' => match self.stream.peek_next() {
+                Some('>') => self.consume(Token::ShiftRight),
                 Some('=') => self.consume(Token::GtEq),
                 _ => Ok(Token::Gt),
             },
 
             '*' => self.consume(Token::Mul),
 
-            '/' => self.consume(Token::Div),
+            '/' => match self.stream.peek_next() {
+                Some('*') => self.tokenize_multi_line_comment(),
+                _ => Ok(Token::Div),
+            },
+
+            '%' => self.consume(Token::Percent),
 
             '+' => self.consume(Token::Plus),
 
-            '-' => self.consume(Token::Minus),
+            '&' => self.consume(Token::BitAnd),
+
+            // Longest match first, `||` must never become two `|` tokens.
+            '|' => match self.stream.peek_next() {
+                Some('|') => self.error(ErrorKind::Other(
+                    "'||' operator is not supported yet".into(),
+                )),
+                _ => Ok(Token::BitOr),
+            },
+
+            '^' => self.consume(Token::BitXor),
+
+            '-' => match self.stream.peek_next() {
+                Some('-') => self.tokenize_single_line_comment(),
+                _ => Ok(Token::Minus),
+            },
 
             '=' => self.consume(Token::Eq),
 
@@ -297,9 +346,15 @@ impl<'i> Tokenizer<'i> {
 
             ';' => self.consume(Token::SemiColon),
 
+            '?' => self.consume(Token::Placeholder),
+
+            ':' | '@' => self.tokenize_named_placeholder(),
+
             '"' | '\'' => self.tokenize_string(),
 
-            '0'..='9' => self.tokenize_number(),
+            '`' => self.tokenize_quoted_identifier(),
+
+            '0'..='9' | '.' => self.tokenize_number(),
 
             _ if Token::is_part_of_ident_or_keyword(chr) => self.tokenize_keyword_or_identifier(),
 
@@ -329,23 +384,162 @@ impl<'i> Tokenizer<'i> {
 
     /// Parses a single quoted or double quoted string like `"this one"` into
     /// [`Token::String`].
+    ///
+    /// The quote character can be escaped inside the string by writing it
+    /// twice, so `'O''Brien'` becomes `O'Brien`.
     fn tokenize_string(&mut self) -> TokenResult {
         let quote = self.stream.next().unwrap();
 
-        let string = self.stream.take_while(|chr| *chr != quote).collect();
+        let mut string = String::new();
 
-        if self.stream.next().is_some_and(|chr| chr == quote) {
-            Ok(Token::String(string))
-        } else {
-            self.error(ErrorKind::StringNotClosed)
+        loop {
+            string.extend(self.stream.take_while(|chr| *chr != quote));
+
+            if self.stream.next().is_none() {
+                return self.error(ErrorKind::StringNotClosed);
+            }
+
+            if self.stream.peek().is_some_and(|chr| *chr == quote) {
+                string.push(self.stream.next().unwrap());
+            } else {
+                return Ok(Token::String(string));
+            }
         }
     }
 
-    /// Tokenizes numbers like `1234`. Floats are not supported.
+    /// Consumes a `-- comment` up to the end of the line.
+    ///
+    /// The first `-` must be consumed before calling this function. The line
+    /// break is not part of the comment, it's returned as a separate token.
+    fn tokenize_single_line_comment(&mut self) -> TokenResult {
+        self.stream.next();
+
+        let comment = self
+            .stream
+            .take_while(|chr| *chr != '\n' && *chr != '\r')
+            .collect();
+
+        Ok(Token::Whitespace(Whitespace::SingleLineComment(comment)))
+    }
+
+    /// Consumes a `/* comment */` which can span multiple lines.
+    ///
+    /// The leading `/` must be consumed before calling this function. Nested
+    /// comments are not supported, the first `*/` closes the comment.
+    fn tokenize_multi_line_comment(&mut self) -> TokenResult {
+        self.stream.next();
+
+        let mut comment = String::new();
+
+        loop {
+            match self.stream.next() {
+                Some('*') if self.stream.peek().is_some_and(|chr| *chr == '/') => {
+                    self.stream.next();
+                    return Ok(Token::Whitespace(Whitespace::MultiLineComment(comment)));
+                }
+                Some(chr) => comment.push(chr),
+                None => return self.error(ErrorKind::CommentNotClosed),
+            }
+        }
+    }
+
+    /// Tokenizes numbers like `1234`, `12.34`, `100.`, `.5`, `1.2e10` or
+    /// `0xFF`.
+    ///
+    /// Whether the number is an integer or a float is decided later by the
+    /// parser based on the presence of the decimal point or the exponent. A
+    /// point without digits around it is not a number but the [`Token::Dot`]
+    /// found in qualified identifiers like `table.column`. Hexadecimal numbers
+    /// keep their `0x` prefix so that the parser knows which base to use.
     fn tokenize_number(&mut self) -> TokenResult {
-        Ok(Token::Number(
-            self.stream.take_while(char::is_ascii_digit).collect(),
-        ))
+        let mut number: String = self.stream.take_while(char::is_ascii_digit).collect();
+
+        if number == "0"
+            && self
+                .stream
+                .peek()
+                .is_some_and(|chr| matches!(chr, 'x' | 'X'))
+        {
+            number.push(self.stream.next().unwrap());
+
+            let digits: String = self.stream.take_while(char::is_ascii_hexdigit).collect();
+
+            if digits.is_empty() {
+                return self.error(ErrorKind::Other(format!("malformed number {number}")));
+            }
+
+            number.push_str(&digits);
+
+            return Ok(Token::Number(number));
+        }
+
+        if self.stream.peek().is_some_and(|chr| *chr == '.') {
+            number.push(self.stream.next().unwrap());
+            number.extend(self.stream.take_while(char::is_ascii_digit));
+        }
+
+        if number == "." {
+            return Ok(Token::Dot);
+        }
+
+        if self
+            .stream
+            .peek()
+            .is_some_and(|chr| matches!(chr, 'e' | 'E'))
+        {
+            number.push(self.stream.next().unwrap());
+
+            if let Some(sign @ ('+' | '-')) = self.stream.peek().copied() {
+                number.push(sign);
+                self.stream.next();
+            }
+
+            let exponent: String = self.stream.take_while(char::is_ascii_digit).collect();
+
+            if exponent.is_empty() {
+                return self.error(ErrorKind::Other(format!("malformed number {number}")));
+            }
+
+            number.push_str(&exponent);
+        }
+
+        if self.stream.peek().is_some_and(|chr| *chr == '.') {
+            number.push('.');
+            return self.error(ErrorKind::Other(format!("malformed number {number}")));
+        }
+
+        Ok(Token::Number(number))
+    }
+
+    /// Parses named parameters like `:user_id` or `@id` into
+    /// [`Token::NamedPlaceholder`].
+    fn tokenize_named_placeholder(&mut self) -> TokenResult {
+        self.stream.next();
+
+        let name: String = self
+            .stream
+            .take_while(Token::is_part_of_ident_or_keyword)
+            .collect();
+
+        if name.is_empty() {
+            return self.error(ErrorKind::Other("expected parameter name".into()));
+        }
+
+        Ok(Token::NamedPlaceholder(name))
+    }
+
+    /// Parses identifiers quoted with backticks like `` `order` `` into
+    /// [`Token::Identifier`] even if they collide with keywords.
+    fn tokenize_quoted_identifier(&mut self) -> TokenResult {
+        self.stream.next();
+
+        let ident = self.stream.take_while(|chr| *chr != '`').collect();
+
+        if self.stream.next().is_some() {
+            Ok(Token::Identifier(ident))
+        } else {
+            self.error(ErrorKind::IdentifierNotClosed)
+        }
     }
 
     /// Attempts to parse an instance of [`Token::Keyword`] or
@@ -377,11 +571,13 @@ impl<'i> Tokenizer<'i> {
             "UNIQUE" => Keyword::Unique,
             "TABLE" => Keyword::Table,
             "DATABASE" => Keyword::Database,
-            "INT" => Keyword::Int,
+            "VIEW" => Keyword::View,
+            "SMALLINT" => Keyword::SmallInt,
+            "INT" | "INTEGER" => Keyword::Int,
             "BIGINT" => Keyword::BigInt,
             "UNSIGNED" => Keyword::Unsigned,
             "VARCHAR" => Keyword::Varchar,
-            "BOOL" => Keyword::Bool,
+            "BOOL" | "BOOLEAN" => Keyword::Bool,
             "TRUE" => Keyword::True,
             "FALSE" => Keyword::False,
             "ORDER" => Keyword::Order,
@@ -393,6 +589,72 @@ impl<'i> Tokenizer<'i> {
             "ROLLBACK" => Keyword::Rollback,
             "COMMIT" => Keyword::Commit,
             "EXPLAIN" => Keyword::Explain,
+            "LIMIT" => Keyword::Limit,
+            "OFFSET" => Keyword::Offset,
+            "GROUP" => Keyword::Group,
+            "HAVING" => Keyword::Having,
+            "DISTINCT" => Keyword::Distinct,
+            "LIKE" => Keyword::Like,
+            "IN" => Keyword::In,
+            "NOT" => Keyword::Not,
+            "BETWEEN" => Keyword::Between,
+            "IS" => Keyword::Is,
+            "NULL" => Keyword::Null,
+            "FLOAT" => Keyword::Float,
+            "REAL" => Keyword::Real,
+            "DOUBLE" => Keyword::Double,
+            "DECIMAL" => Keyword::Decimal,
+            "NUMERIC" => Keyword::Numeric,
+            "DATE" => Keyword::Date,
+            "TIMESTAMP" => Keyword::Timestamp,
+            "TEXT" => Keyword::Text,
+            "DEFAULT" => Keyword::Default,
+            "FOREIGN" => Keyword::Foreign,
+            "REFERENCES" => Keyword::References,
+            "CHECK" => Keyword::Check,
+            "AUTO_INCREMENT" | "AUTOINCREMENT" => Keyword::AutoIncrement,
+            "ALTER" => Keyword::Alter,
+            "ADD" => Keyword::Add,
+            "COLUMN" => Keyword::Column,
+            "RENAME" => Keyword::Rename,
+            "TO" => Keyword::To,
+            "IF" => Keyword::If,
+            "EXISTS" => Keyword::Exists,
+            "AS" => Keyword::As,
+            "JOIN" => Keyword::Join,
+            "INNER" => Keyword::Inner,
+            "LEFT" => Keyword::Left,
+            "RIGHT" => Keyword::Right,
+            "FULL" => Keyword::Full,
+            "OUTER" => Keyword::Outer,
+            "UNION" => Keyword::Union,
+            "INTERSECT" => Keyword::Intersect,
+            "EXCEPT" => Keyword::Except,
+            "ALL" => Keyword::All,
+            "RETURNING" => Keyword::Returning,
+            "TRUNCATE" => Keyword::Truncate,
+            "BEGIN" => Keyword::Begin,
+            "SAVEPOINT" => Keyword::Savepoint,
+            "CASE" => Keyword::Case,
+            "WHEN" => Keyword::When,
+            "THEN" => Keyword::Then,
+            "ELSE" => Keyword::Else,
+            "END" => Keyword::End,
+            "CAST" => Keyword::Cast,
+            "SHOW" => Keyword::Show,
+            "TABLES" => Keyword::Tables,
+            "COLUMNS" => Keyword::Columns,
+            "DESCRIBE" => Keyword::Describe,
+            "DESC" => Keyword::Desc,
+            "ASC" => Keyword::Asc,
+            "NULLS" => Keyword::Nulls,
+            "FIRST" => Keyword::First,
+            "LAST" => Keyword::Last,
+            "USE" => Keyword::Use,
+            "WITH" => Keyword::With,
+            "ANY" => Keyword::Any,
+            "ESCAPE" => Keyword::Escape,
+            "ILIKE" => Keyword::ILike,
             _ => Keyword::None,
         };
 
@@ -720,6 +982,387 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_single_and_double_quoted_strings_equally() {
+        for sql in ["'hello'", "\"hello\""] {
+            assert_eq!(
+                Tokenizer::new(sql).tokenize(),
+                Ok(vec![Token::String("hello".into()), Token::Eof])
+            );
+        }
+    }
+
+    #[test]
+    fn tokenize_escaped_quotes() {
+        assert_eq!(
+            Tokenizer::new(r#"'O''Brien' "say ""hi""" 'a''''b'"#).tokenize(),
+            Ok(vec![
+                Token::String("O'Brien".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::String("say \"hi\"".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::String("a''b".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_empty_string() {
+        assert_eq!(
+            Tokenizer::new("'' \"\"").tokenize(),
+            Ok(vec![
+                Token::String("".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::String("".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_quoted_identifiers() {
+        assert_eq!(
+            Tokenizer::new("`order` order").tokenize(),
+            Ok(vec![
+                Token::Identifier("order".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Keyword(Keyword::Order),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_null_keyword() {
+        assert_eq!(
+            Tokenizer::new("NULL null").tokenize(),
+            Ok(vec![
+                Token::Keyword(Keyword::Null),
+                Token::Whitespace(Whitespace::Space),
+                Token::Keyword(Keyword::Null),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_bool_keywords() {
+        assert_eq!(
+            Tokenizer::new("TRUE false True").tokenize(),
+            Ok(vec![
+                Token::Keyword(Keyword::True),
+                Token::Whitespace(Whitespace::Space),
+                Token::Keyword(Keyword::False),
+                Token::Whitespace(Whitespace::Space),
+                Token::Keyword(Keyword::True),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_boolean_alias() {
+        assert_eq!(
+            Tokenizer::new("BOOL boolean").tokenize(),
+            Ok(vec![
+                Token::Keyword(Keyword::Bool),
+                Token::Whitespace(Whitespace::Space),
+                Token::Keyword(Keyword::Bool),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_floats() {
+        assert_eq!(
+            Tokenizer::new("3.14 100. .5").tokenize(),
+            Ok(vec![
+                Token::Number("3.14".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("100.".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number(".5".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_scientific_notation() {
+        assert_eq!(
+            Tokenizer::new("1.2e10 5E3 .5e-2 1e+7").tokenize(),
+            Ok(vec![
+                Token::Number("1.2e10".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("5E3".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number(".5e-2".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1e+7".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_hex_numbers() {
+        assert_eq!(
+            Tokenizer::new("0xFF 0X1a 0x10+1").tokenize(),
+            Ok(vec![
+                Token::Number("0xFF".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("0X1a".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("0x10".into()),
+                Token::Plus,
+                Token::Number("1".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_with_spans() {
+        let tokens = Tokenizer::new("SELECT customer_id\nFROM t;")
+            .iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let spans: Vec<_> = tokens
+            .iter()
+            .map(|token| (token.location, token.end))
+            .collect();
+
+        assert_eq!(spans, vec![
+            (Location { line: 1, col: 1 }, Location { line: 1, col: 7 }),
+            (Location { line: 1, col: 7 }, Location { line: 1, col: 8 }),
+            (Location { line: 1, col: 8 }, Location { line: 1, col: 19 }),
+            (Location { line: 1, col: 19 }, Location { line: 2, col: 1 }),
+            (Location { line: 2, col: 1 }, Location { line: 2, col: 5 }),
+            (Location { line: 2, col: 5 }, Location { line: 2, col: 6 }),
+            (Location { line: 2, col: 6 }, Location { line: 2, col: 7 }),
+            (Location { line: 2, col: 7 }, Location { line: 2, col: 8 }),
+            (Location { line: 2, col: 8 }, Location { line: 2, col: 8 }),
+        ]);
+    }
+
+    #[test]
+    fn tokenize_malformed_numbers() {
+        for (sql, number, col) in [
+            ("SELECT 0x;", "0x", 10),
+            ("SELECT 1.2.3;", "1.2.", 11),
+            ("SELECT 1e;", "1e", 10),
+            ("SELECT 1.5e-;", "1.5e-", 13),
+        ] {
+            assert_eq!(
+                Tokenizer::new(sql).tokenize(),
+                Err(TokenizerError {
+                    kind: ErrorKind::Other(format!("malformed number {number}")),
+                    location: Location { line: 1, col },
+                    input: sql.to_owned(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn tokenize_qualified_identifiers() {
+        assert_eq!(
+            Tokenizer::new("users.id users.*").tokenize(),
+            Ok(vec![
+                Token::Identifier("users".into()),
+                Token::Dot,
+                Token::Identifier("id".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Identifier("users".into()),
+                Token::Dot,
+                Token::Mul,
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_arithmetic_operators() {
+        assert_eq!(
+            Tokenizer::new("a%b*c").tokenize(),
+            Ok(vec![
+                Token::Identifier("a".into()),
+                Token::Percent,
+                Token::Identifier("b".into()),
+                Token::Mul,
+                Token::Identifier("c".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_bitwise_operators() {
+        assert_eq!(
+            Tokenizer::new("a&b|c^d<<e>>f<g>h").tokenize(),
+            Ok(vec![
+                Token::Identifier("a".into()),
+                Token::BitAnd,
+                Token::Identifier("b".into()),
+                Token::BitOr,
+                Token::Identifier("c".into()),
+                Token::BitXor,
+                Token::Identifier("d".into()),
+                Token::ShiftLeft,
+                Token::Identifier("e".into()),
+                Token::ShiftRight,
+                Token::Identifier("f".into()),
+                Token::Lt,
+                Token::Identifier("g".into()),
+                Token::Gt,
+                Token::Identifier("h".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_unsupported_concat_operator() {
+        let sql = "SELECT a || b;";
+        assert_eq!(
+            Tokenizer::new(sql).tokenize(),
+            Err(TokenizerError {
+                kind: ErrorKind::Other("'||' operator is not supported yet".into()),
+                location: Location { line: 1, col: 11 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_neq_operators() {
+        assert_eq!(
+            Tokenizer::new("a != b <> c").tokenize(),
+            Ok(vec![
+                Token::Identifier("a".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Neq,
+                Token::Whitespace(Whitespace::Space),
+                Token::Identifier("b".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Neq,
+                Token::Whitespace(Whitespace::Space),
+                Token::Identifier("c".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_single_line_comment() {
+        assert_eq!(
+            Tokenizer::new("SELECT 1 - 2; -- comment\nSELECT").tokenize(),
+            Ok(vec![
+                Token::Keyword(Keyword::Select),
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("1".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Minus,
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("2".into()),
+                Token::SemiColon,
+                Token::Whitespace(Whitespace::Space),
+                Token::Whitespace(Whitespace::SingleLineComment(" comment".into())),
+                Token::Whitespace(Whitespace::Newline),
+                Token::Keyword(Keyword::Select),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_comment_only() {
+        assert_eq!(
+            Tokenizer::new("--SELECT * FROM users;").tokenize(),
+            Ok(vec![
+                Token::Whitespace(Whitespace::SingleLineComment("SELECT * FROM users;".into())),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_inline_multi_line_comment() {
+        assert_eq!(
+            Tokenizer::new("2 /* times */ * 3 / 4").tokenize(),
+            Ok(vec![
+                Token::Number("2".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Whitespace(Whitespace::MultiLineComment(" times ".into())),
+                Token::Whitespace(Whitespace::Space),
+                Token::Mul,
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("3".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Div,
+                Token::Whitespace(Whitespace::Space),
+                Token::Number("4".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_multi_line_comment() {
+        assert_eq!(
+            Tokenizer::new("/* first\n * second\n */SELECT").tokenize(),
+            Ok(vec![
+                Token::Whitespace(Whitespace::MultiLineComment(" first\n * second\n ".into())),
+                Token::Keyword(Keyword::Select),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_placeholders() {
+        assert_eq!(
+            Tokenizer::new("id = ?").tokenize(),
+            Ok(vec![
+                Token::Identifier("id".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::Eq,
+                Token::Whitespace(Whitespace::Space),
+                Token::Placeholder,
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_named_placeholders() {
+        assert_eq!(
+            Tokenizer::new(":user_id @id").tokenize(),
+            Ok(vec![
+                Token::NamedPlaceholder("user_id".into()),
+                Token::Whitespace(Whitespace::Space),
+                Token::NamedPlaceholder("id".into()),
+                Token::Eof
+            ])
+        );
+    }
+
+    #[test]
+    fn tokenize_named_placeholder_without_name() {
+        let sql = "SELECT * FROM users WHERE id = : ;";
+        assert_eq!(
+            Tokenizer::new(sql).tokenize(),
+            Err(TokenizerError {
+                kind: ErrorKind::Other("expected parameter name".into()),
+                location: Location { line: 1, col: 33 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
     #[test]
     fn tokenize_incorrect_neq_operator() {
         let sql = "SELECT * FROM table WHERE column ! other";
@@ -762,6 +1405,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_error_with_location() {
+        let sql = "SELECT *\nFROM users\nWHERE name = 'not closed";
+
+        assert_eq!(
+            Tokenizer::new(sql).tokenize().unwrap_err().to_string(),
+            "Tokenizer Error at line 3 column 25: string not closed"
+        );
+    }
+
     #[test]
     fn tokenize_single_quoted_string_not_closed() {
         let sql = "SELECT * FROM table WHERE string = 'not closed";
@@ -775,13 +1428,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_string_ending_with_escaped_quote_not_closed() {
+        let sql = "SELECT * FROM users WHERE name = 'O''";
+        assert_eq!(
+            Tokenizer::new(sql).tokenize(),
+            Err(TokenizerError {
+                kind: ErrorKind::StringNotClosed,
+                location: Location { line: 1, col: 38 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_multi_line_comment_not_closed() {
+        let sql = "SELECT * FROM users;\n/* not\nclosed *";
+        assert_eq!(
+            Tokenizer::new(sql).tokenize(),
+            Err(TokenizerError {
+                kind: ErrorKind::CommentNotClosed,
+                location: Location { line: 3, col: 9 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn tokenize_quoted_identifier_not_closed() {
+        let sql = "SELECT `order FROM orders;";
+        assert_eq!(
+            Tokenizer::new(sql).tokenize(),
+            Err(TokenizerError {
+                kind: ErrorKind::IdentifierNotClosed,
+                location: Location { line: 1, col: 27 },
+                input: sql.to_owned(),
+            })
+        );
+    }
+
     #[test]
     fn tokenize_unsupported_token() {
-        let sql = "SELECT * FROM ^ WHERE unsupported = 1;";
+        let sql = "SELECT * FROM # WHERE unsupported = 1;";
         assert_eq!(
             Tokenizer::new(sql).tokenize(),
             Err(TokenizerError {
-                kind: ErrorKind::UnexpectedOrUnsupportedToken('^'),
+                kind: ErrorKind::UnexpectedOrUnsupportedToken('#'),
                 location: Location { line: 1, col: 15 },
                 input: sql.to_owned(),
             })
@@ -0,0 +1,419 @@
+//! Read-only traversal of [`Expression`] trees.
+//!
+//! Implement [`ExpressionVisitor`] overriding only the methods you care about
+//! and then call [`walk`] to traverse the tree. The visitor is notified of
+//! nodes in the same order they appear in the SQL source, so `a + b` visits
+//! `a`, then `+`, then `b`.
+//!
+//! The analyzer rejects joins, subqueries, CTEs and qualified identifiers,
+//! which are the cases where we'd need to traverse whole statements, so for
+//! now this module is only compiled for tests.
+
+use std::collections::HashSet;
+
+use super::statement::{
+    BinaryOperator, Create, Expression, InsertSource, Select, Statement, TableReference,
+    UnaryOperator, Value,
+};
+
+/// Callbacks invoked by [`walk`]. All of them do nothing by default.
+pub(crate) trait ExpressionVisitor {
+    /// Called for every node before any of the more specific methods below.
+    fn visit_expression(&mut self, _expr: &Expression) {}
+
+    /// [`Expression::Identifier`].
+    fn visit_identifier(&mut self, _ident: &str) {}
+
+    /// [`Expression::QualifiedIdentifier`].
+    fn visit_qualified_identifier(&mut self, _table: &str, _column: &str) {}
+
+    /// [`Expression::Value`].
+    fn visit_value(&mut self, _value: &Value) {}
+
+    /// Operator of [`Expression::BinaryOperation`], visited between the left
+    /// and right operands.
+    fn visit_binary_op(&mut self, _operator: BinaryOperator) {}
+
+    /// Operator of [`Expression::UnaryOperation`], visited before the operand.
+    fn visit_unary_op(&mut self, _operator: UnaryOperator) {}
+
+    /// Name of [`Expression::Function`], visited before the arguments.
+    fn visit_function(&mut self, _name: &str) {}
+
+    /// Statements inside [`Expression::Subquery`], [`Expression::Exists`],
+    /// [`Expression::InSubquery`] and [`Expression::Quantified`]. [`walk`]
+    /// doesn't descend into them.
+    fn visit_subquery(&mut self, _subquery: &Statement) {}
+}
+
+/// Traverses `expr` recursively calling the `visitor` methods on each node.
+pub(crate) fn walk(expr: &Expression, visitor: &mut impl ExpressionVisitor) {
+    visitor.visit_expression(expr);
+
+    match expr {
+        Expression::Identifier(ident) => visitor.visit_identifier(ident),
+
+        Expression::QualifiedIdentifier { table, column } => {
+            visitor.visit_qualified_identifier(table, column)
+        }
+
+        Expression::Value(value) => visitor.visit_value(value),
+
+        Expression::Wildcard
+        | Expression::QualifiedWildcard(_)
+        | Expression::Parameter(_)
+        | Expression::NamedParameter(_) => {}
+
+        Expression::BinaryOperation {
+            left,
+            operator,
+            right,
+        } => {
+            walk(left, visitor);
+            visitor.visit_binary_op(*operator);
+            walk(right, visitor);
+        }
+
+        Expression::UnaryOperation { operator, expr } => {
+            visitor.visit_unary_op(*operator);
+            walk(expr, visitor);
+        }
+
+        Expression::Nested(expr)
+        | Expression::IsNull { expr, .. }
+        | Expression::Cast { expr, .. } => walk(expr, visitor),
+
+        Expression::Function { name, args, .. } => {
+            visitor.visit_function(name);
+            args.iter().for_each(|arg| walk(arg, visitor));
+        }
+
+        Expression::Row(items) => items.iter().for_each(|item| walk(item, visitor)),
+
+        Expression::InList { expr, list, .. } => {
+            walk(expr, visitor);
+            list.iter().for_each(|item| walk(item, visitor));
+        }
+
+        Expression::Like { expr, pattern, .. } => {
+            walk(expr, visitor);
+            walk(pattern, visitor);
+        }
+
+        Expression::Between {
+            expr, low, high, ..
+        } => {
+            walk(expr, visitor);
+            walk(low, visitor);
+            walk(high, visitor);
+        }
+
+        Expression::Subquery(subquery) | Expression::Exists { subquery, .. } => {
+            visitor.visit_subquery(subquery)
+        }
+
+        Expression::InSubquery { expr, subquery, .. } => {
+            walk(expr, visitor);
+            visitor.visit_subquery(subquery);
+        }
+
+        Expression::Quantified {
+            left,
+            operator,
+            subquery,
+            ..
+        } => {
+            walk(left, visitor);
+            visitor.visit_binary_op(*operator);
+            visitor.visit_subquery(subquery);
+        }
+
+        Expression::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            if let Some(operand) = operand {
+                walk(operand, visitor);
+            }
+
+            for (when, then) in when_then {
+                walk(when, visitor);
+                walk(then, visitor);
+            }
+
+            if let Some(else_result) = else_result {
+                walk(else_result, visitor);
+            }
+        }
+    }
+}
+
+impl Statement {
+    /// Returns the names of all the columns that this statement reads or
+    /// writes, including the ones referenced inside subqueries.
+    ///
+    /// Qualified identifiers are returned as `table.column`.
+    pub(crate) fn referenced_columns(&self) -> HashSet<String> {
+        let mut collector = ColumnCollector::default();
+        collector.collect(self);
+
+        collector.columns
+    }
+}
+
+/// [`ExpressionVisitor`] behind [`Statement::referenced_columns`].
+#[derive(Default)]
+struct ColumnCollector {
+    columns: HashSet<String>,
+}
+
+impl ColumnCollector {
+    /// Walks all the expressions found in `statement`.
+    fn collect(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Select(select) => {
+                let Select {
+                    ctes,
+                    columns,
+                    from,
+                    joins,
+                    r#where,
+                    group_by,
+                    having,
+                    order_by,
+                    limit,
+                    offset,
+                    ..
+                } = &**select;
+
+                ctes.iter().for_each(|cte| self.collect(&cte.query));
+                columns.iter().for_each(|item| walk(&item.expr, self));
+
+                if let Some(from) = from {
+                    self.collect_table_reference(from);
+                }
+
+                for join in joins {
+                    self.collect_table_reference(&join.table);
+                    walk(&join.on, self);
+                }
+
+                [r#where, having, limit, offset]
+                    .into_iter()
+                    .flatten()
+                    .chain(group_by)
+                    .chain(order_by.iter().map(|key| &key.expr))
+                    .for_each(|expr| walk(expr, self));
+            }
+
+            Statement::Delete {
+                from,
+                r#where,
+                returning,
+            } => {
+                self.collect_table_reference(from);
+                r#where
+                    .iter()
+                    .chain(returning)
+                    .for_each(|expr| walk(expr, self));
+            }
+
+            Statement::Update {
+                columns,
+                r#where,
+                returning,
+                ..
+            } => {
+                for assignment in columns {
+                    self.columns.insert(assignment.identifier.clone());
+                    walk(&assignment.value, self);
+                }
+
+                r#where
+                    .iter()
+                    .chain(returning)
+                    .for_each(|expr| walk(expr, self));
+            }
+
+            Statement::Insert {
+                columns,
+                source,
+                returning,
+                ..
+            } => {
+                self.columns.extend(columns.iter().cloned());
+
+                match source {
+                    InsertSource::Values(rows) => {
+                        rows.iter().flatten().for_each(|expr| walk(expr, self))
+                    }
+                    InsertSource::Select(query) => self.collect(query),
+                }
+
+                returning.iter().for_each(|expr| walk(expr, self));
+            }
+
+            Statement::SetOperation { left, right, .. } => {
+                self.collect(left);
+                self.collect(right);
+            }
+
+            Statement::Explain(statement)
+            | Statement::Create(Create::View {
+                query: statement, ..
+            }) => self.collect(statement),
+
+            _ => {}
+        }
+    }
+
+    /// Derived tables are the only references that contain expressions.
+    fn collect_table_reference(&mut self, reference: &TableReference) {
+        if let TableReference::Subquery { query, .. } = reference {
+            self.collect(query);
+        }
+    }
+}
+
+impl ExpressionVisitor for ColumnCollector {
+    fn visit_identifier(&mut self, ident: &str) {
+        self.columns.insert(ident.into());
+    }
+
+    fn visit_qualified_identifier(&mut self, table: &str, column: &str) {
+        self.columns.insert(format!("{table}.{column}"));
+    }
+
+    fn visit_subquery(&mut self, subquery: &Statement) {
+        self.collect(subquery);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{walk, ExpressionVisitor};
+    use crate::sql::{
+        parser::{Parser, ParserError},
+        statement::{BinaryOperator, Statement, Value},
+    };
+
+    fn referenced_columns(sql: &str) -> Result<HashSet<String>, ParserError> {
+        Ok(Parser::new(sql).parse_statement()?.referenced_columns())
+    }
+
+    #[derive(Default)]
+    struct Identifiers(Vec<String>);
+
+    impl ExpressionVisitor for Identifiers {
+        fn visit_identifier(&mut self, ident: &str) {
+            self.0.push(ident.into());
+        }
+
+        fn visit_qualified_identifier(&mut self, table: &str, column: &str) {
+            self.0.push(format!("{table}.{column}"));
+        }
+    }
+
+    #[test]
+    fn collect_identifiers() -> Result<(), ParserError> {
+        let expr = Parser::new(
+            "(age BETWEEN 18 AND max_age OR u.vip) AND name IN ('a', nickname) AND NOT LOWER(email) LIKE '%@test.com' AND CASE status WHEN 1 THEN TRUE ELSE score > 10 END",
+        )
+        .parse_expression()?;
+
+        let mut identifiers = Identifiers::default();
+        walk(&expr, &mut identifiers);
+
+        assert_eq!(identifiers.0, [
+            "age", "max_age", "u.vip", "name", "nickname", "email", "status", "score"
+        ]);
+
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct Trace(Vec<String>);
+
+    impl ExpressionVisitor for Trace {
+        fn visit_identifier(&mut self, ident: &str) {
+            self.0.push(ident.into());
+        }
+
+        fn visit_value(&mut self, value: &Value) {
+            self.0.push(value.to_string());
+        }
+
+        fn visit_binary_op(&mut self, operator: BinaryOperator) {
+            self.0.push(operator.to_string());
+        }
+
+        fn visit_subquery(&mut self, _subquery: &Statement) {
+            self.0.push("subquery".into());
+        }
+    }
+
+    #[test]
+    fn walk_in_source_order() -> Result<(), ParserError> {
+        let expr = Parser::new("x + 2 * y = 10 AND id IN (SELECT id FROM t)").parse_expression()?;
+
+        let mut trace = Trace::default();
+        walk(&expr, &mut trace);
+
+        assert_eq!(trace.0, [
+            "x", "+", "2", "*", "y", "=", "10", "AND", "id", "subquery"
+        ]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn referenced_columns_in_select() -> Result<(), ParserError> {
+        assert_eq!(
+            referenced_columns(
+                "SELECT id, price * (1 - discount) AS total FROM products WHERE stock > 0 AND category IN ('a', 'b') ORDER BY name;"
+            )?,
+            HashSet::from(["id", "price", "discount", "stock", "category", "name"].map(String::from))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn referenced_columns_in_joins_and_subqueries() -> Result<(), ParserError> {
+        assert_eq!(
+            referenced_columns(
+                "SELECT u.name, COUNT(*) FROM users u JOIN orders o ON u.id = o.user_id WHERE o.item IN (SELECT id FROM items WHERE stock > 0) GROUP BY u.name HAVING COUNT(*) > 1;"
+            )?,
+            HashSet::from(
+                ["u.name", "u.id", "o.user_id", "o.item", "id", "stock"].map(String::from)
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn referenced_columns_in_ctes() -> Result<(), ParserError> {
+        assert_eq!(
+            referenced_columns(
+                "WITH adults AS (SELECT id, name FROM users WHERE age >= 18) SELECT name FROM adults;"
+            )?,
+            HashSet::from(["id", "name", "age"].map(String::from))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn referenced_columns_in_update() -> Result<(), ParserError> {
+        assert_eq!(
+            referenced_columns("UPDATE users SET age = age + 1 WHERE id = 5 RETURNING name;")?,
+            HashSet::from(["age", "id", "name"].map(String::from))
+        );
+
+        Ok(())
+    }
+}
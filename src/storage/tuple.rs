@@ -81,6 +81,7 @@ pub(crate) fn serialize_row_id(row_id: RowId) -> [u8; mem::size_of::<RowId>()] {
 /// Returns the byte length of the given data type. Only works with integers.
 pub(crate) fn byte_length_of_integer_type(data_type: &DataType) -> usize {
     match data_type {
+        DataType::SmallInt | DataType::UnsignedSmallInt => 2,
         DataType::Int | DataType::UnsignedInt => 4,
         DataType::BigInt | DataType::UnsignedBigInt => 8,
         _ => unreachable!("byte_length_of_integer_type() called with incorrect {data_type:?}"),
@@ -105,6 +106,8 @@ pub(crate) fn utf8_length_prefix_bytes(max_characters: usize) -> usize {
 /// variants.
 pub(crate) fn integer_is_within_range(integer: &i128, integer_type: &DataType) -> bool {
     let bounds = match integer_type {
+        DataType::SmallInt => i16::MIN as i128..=i16::MAX as i128,
+        DataType::UnsignedSmallInt => 0..=u16::MAX as i128,
         DataType::Int => i32::MIN as i128..=i32::MAX as i128,
         DataType::UnsignedInt => 0..=u32::MAX as i128,
         DataType::BigInt => i64::MIN as i128..=i64::MAX as i128,
@@ -255,7 +258,10 @@ pub fn read_from(reader: &mut impl Read, schema: &Schema) -> io::Result<Vec<Valu
 
                 // Adjustment for negative numbers. Gotta love two's complement.
                 if big_endian_buf[start_index] & 0x80 != 0
-                    && matches!(integer_type, DataType::BigInt | DataType::Int)
+                    && matches!(
+                        integer_type,
+                        DataType::BigInt | DataType::Int | DataType::SmallInt
+                    )
                 {
                     big_endian_buf[..start_index].fill(u8::MAX);
                 }
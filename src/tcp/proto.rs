@@ -109,6 +109,13 @@
 //!     DataType::BigInt => 3,
 //!     DataType::UnsignedBigInt => 4,
 //!     DataType::Varchar(_) => 5,
+//!     DataType::Float => 6,
+//!     DataType::Decimal { .. } => 7,
+//!     DataType::SmallInt => 8,
+//!     DataType::UnsignedSmallInt => 9,
+//!     DataType::Date => 10,
+//!     DataType::Timestamp => 11,
+//!     DataType::Text => 12,
 //! }
 //! ```
 //!
@@ -126,6 +133,9 @@
 //!  Endian                         Endian
 //! ```
 //!
+//! `DECIMAL` types are followed by two more bytes instead, the precision and
+//! then the scale.
+//!
 //! Finally, after all the columns, the response packet encodes the tuple
 //! results prefixed by a 4 byte little endian integer that indicates the total
 //! number of tuples. Tuples are encoded using the exact same format that we
@@ -283,10 +293,20 @@ pub fn serialize(payload: &Response) -> Result<Vec<u8>, EncodingError> {
                     DataType::BigInt => 3,
                     DataType::UnsignedBigInt => 4,
                     DataType::Varchar(_) => 5,
+                    DataType::Float => 6,
+                    DataType::Decimal { .. } => 7,
+                    DataType::SmallInt => 8,
+                    DataType::UnsignedSmallInt => 9,
+                    DataType::Date => 10,
+                    DataType::Timestamp => 11,
+                    DataType::Text => 12,
                 });
                 if let DataType::Varchar(max_characters) = col.data_type {
                     packet.extend_from_slice(&(max_characters as u32).to_le_bytes());
                 }
+                if let DataType::Decimal { precision, scale } = col.data_type {
+                    packet.extend_from_slice(&[precision, scale]);
+                }
             }
             packet.extend_from_slice(&(u32::try_from(query_set.tuples.len())?).to_le_bytes());
             for tuple in &query_set.tuples {
@@ -341,6 +361,19 @@ pub fn deserialize(payload: &[u8]) -> Result<Response, EncodingError> {
 
                         DataType::Varchar(max_chars)
                     }
+                    6 => DataType::Float,
+                    7 => {
+                        let precision = payload[cursor + 1];
+                        let scale = payload[cursor + 2];
+                        cursor += 2;
+
+                        DataType::Decimal { precision, scale }
+                    }
+                    8 => DataType::SmallInt,
+                    9 => DataType::UnsignedSmallInt,
+                    10 => DataType::Date,
+                    11 => DataType::Timestamp,
+                    12 => DataType::Text,
                     invalid => Err(EncodingError::InvalidDataType(invalid))?,
                 };
                 cursor += 1;
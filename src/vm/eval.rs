@@ -0,0 +1,193 @@
+//! Evaluation of [`Expression`] trees against rows that don't come from any
+//! table.
+//!
+//! The VM resolves expressions against tuples and schemas, but sometimes we
+//! only have a bunch of named values. This module reuses the same semantics
+//! as [`super::resolve_expression`] with a simpler error type.
+//!
+//! Nothing in the query path needs this yet, so it's only compiled for tests,
+//! where it's handy for checking expression semantics without a schema.
+
+use std::{collections::HashMap, fmt::Display};
+
+use super::{expression, TypeError, VmError};
+use crate::{
+    db::SqlError,
+    sql::statement::{Expression, Value},
+};
+
+/// Errors that can happen while evaluating an expression with [`evaluate`].
+#[derive(Debug, PartialEq)]
+pub(crate) enum EvalError {
+    /// Identifier not present in the row.
+    UnknownIdentifier(String),
+    /// Operator applied to values of the wrong type.
+    TypeMismatch(TypeError),
+    /// Division or modulo where the right side is zero.
+    DivisionByZero,
+    /// Integer result doesn't fit in [`Value::Number`].
+    Overflow,
+    /// Everything else, like expressions that can't be evaluated yet.
+    Other(String),
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnknownIdentifier(ident) => write!(f, "unknown identifier '{ident}'"),
+            Self::TypeMismatch(type_error) => write!(f, "{type_error}"),
+            Self::DivisionByZero => f.write_str("division by zero"),
+            Self::Overflow => f.write_str("integer overflow"),
+            Self::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<SqlError> for EvalError {
+    fn from(sql_error: SqlError) -> Self {
+        match sql_error {
+            SqlError::InvalidColumn(ident) => Self::UnknownIdentifier(ident),
+            SqlError::TypeError(type_error) => Self::TypeMismatch(type_error),
+            SqlError::VmError(VmError::DivisionByZero(..)) => Self::DivisionByZero,
+            SqlError::VmError(VmError::Overflow { .. }) => Self::Overflow,
+            SqlError::Other(message) => Self::Other(message),
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// Evaluates `expr` looking up identifiers in `row`.
+///
+/// Arithmetic operators only work on numbers, comparisons return booleans and
+/// `AND` / `OR` short-circuit, so `FALSE AND x` is `FALSE` even if `x` is not
+/// defined.
+pub(crate) fn evaluate(
+    expr: &Expression,
+    row: &HashMap<String, Value>,
+) -> Result<Value, EvalError> {
+    Ok(expression::resolve(row, expr)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{evaluate, EvalError};
+    use crate::{
+        sql::{
+            parser::Parser,
+            statement::{BinaryOperator, Expression, Value},
+        },
+        vm::TypeError,
+    };
+
+    fn eval(expr: &str, row: &[(&str, Value)]) -> Result<Value, EvalError> {
+        let row = HashMap::from_iter(
+            row.iter()
+                .map(|(name, value)| (String::from(*name), value.clone())),
+        );
+
+        evaluate(&Parser::new(expr).parse_expression().unwrap(), &row)
+    }
+
+    #[test]
+    fn evaluate_arithmetic() {
+        let row = [("x", Value::Number(10)), ("y", Value::Number(3))];
+
+        assert_eq!(eval("x + y * 2", &row), Ok(Value::Number(16)));
+        assert_eq!(eval("(x - y) / 2", &row), Ok(Value::Number(3)));
+        assert_eq!(eval("x % y", &row), Ok(Value::Number(1)));
+        assert_eq!(eval("-x + 1", &row), Ok(Value::Number(-9)));
+    }
+
+    #[test]
+    fn evaluate_comparisons() {
+        let row = [
+            ("x", Value::Number(10)),
+            ("name", Value::String("mkdb".into())),
+        ];
+
+        assert_eq!(eval("x > 5", &row), Ok(Value::Bool(true)));
+        assert_eq!(eval("x <= 5", &row), Ok(Value::Bool(false)));
+        assert_eq!(eval("x != 10", &row), Ok(Value::Bool(false)));
+        assert_eq!(eval("name = 'mkdb'", &row), Ok(Value::Bool(true)));
+        assert_eq!(eval("name < 'z'", &row), Ok(Value::Bool(true)));
+    }
+
+    #[test]
+    fn evaluate_boolean_operators() {
+        let row = [("a", Value::Bool(true)), ("b", Value::Bool(false))];
+
+        assert_eq!(eval("a AND b", &row), Ok(Value::Bool(false)));
+        assert_eq!(eval("a OR b", &row), Ok(Value::Bool(true)));
+        assert_eq!(eval("NOT b AND a", &row), Ok(Value::Bool(true)));
+        assert_eq!(eval("b OR NOT a", &row), Ok(Value::Bool(false)));
+    }
+
+    #[test]
+    fn boolean_operators_short_circuit() {
+        let row = [("a", Value::Bool(true)), ("b", Value::Bool(false))];
+
+        assert_eq!(eval("b AND unknown", &row), Ok(Value::Bool(false)));
+        assert_eq!(eval("a OR 1 / 0 = 1", &row), Ok(Value::Bool(true)));
+        assert_eq!(
+            eval("a AND unknown", &row),
+            Err(EvalError::UnknownIdentifier("unknown".into()))
+        );
+    }
+
+    #[test]
+    fn evaluate_errors() {
+        let row = [("x", Value::Number(10)), ("s", Value::String("s".into()))];
+
+        assert_eq!(
+            eval("y + 1", &row),
+            Err(EvalError::UnknownIdentifier("y".into()))
+        );
+        assert_eq!(
+            eval("x + s", &row),
+            Err(EvalError::TypeMismatch(TypeError::CannotApplyBinary {
+                left: Box::new(Expression::Value(Value::Number(10))),
+                operator: BinaryOperator::Plus,
+                right: Box::new(Expression::Value(Value::String("s".into()))),
+            }))
+        );
+        assert_eq!(eval("x / 0", &row), Err(EvalError::DivisionByZero));
+    }
+
+    #[test]
+    fn evaluate_integer_division() {
+        let row = [("a", Value::Number(17)), ("b", Value::Number(5))];
+
+        assert_eq!(eval("a / b", &row), Ok(Value::Number(3)));
+        assert_eq!(eval("-a / b", &row), Ok(Value::Number(-3)));
+        assert_eq!(eval("a % b", &row), Ok(Value::Number(2)));
+        assert_eq!(eval("-a % b", &row), Ok(Value::Number(-2)));
+        assert_eq!(eval("a % 0", &row), Err(EvalError::DivisionByZero));
+    }
+
+    #[test]
+    fn evaluate_overflow() {
+        let row = [
+            ("max", Value::Number(i128::MAX)),
+            ("min", Value::Number(i128::MIN)),
+        ];
+
+        assert_eq!(eval("max + 1", &row), Err(EvalError::Overflow));
+        assert_eq!(eval("min - 1", &row), Err(EvalError::Overflow));
+        assert_eq!(eval("max * 2", &row), Err(EvalError::Overflow));
+        assert_eq!(eval("min / -1", &row), Err(EvalError::Overflow));
+        assert_eq!(eval("min % -1", &row), Err(EvalError::Overflow));
+        assert_eq!(eval("max - max", &row), Ok(Value::Number(0)));
+    }
+
+    #[test]
+    fn evaluate_type_mismatch() {
+        let row = [("t", Value::Bool(true))];
+
+        assert!(matches!(
+            eval("t * 1", &row),
+            Err(EvalError::TypeMismatch(_))
+        ));
+    }
+}
@@ -1,6 +1,6 @@
 //! Code that executes [`Expression`] trees and resolves them into [`Value`].
 
-use std::{fmt::Display, mem};
+use std::{collections::HashMap, fmt::Display, mem};
 
 use crate::{
     db::{Schema, SqlError},
@@ -42,12 +42,23 @@ impl From<DataType> for VmDataType {
 #[derive(Debug, PartialEq)]
 pub(crate) enum VmError {
     DivisionByZero(i128, i128),
+    Overflow {
+        left: i128,
+        operator: BinaryOperator,
+        right: i128,
+    },
 }
 
 impl Display for VmError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::DivisionByZero(left, right) => write!(f, "division by zero: {left} / {right}"),
+
+            Self::Overflow {
+                left,
+                operator,
+                right,
+            } => write!(f, "integer overflow: {left} {operator} {right}"),
         }
     }
 }
@@ -63,10 +74,12 @@ pub(crate) enum TypeError {
         operator: UnaryOperator,
         value: Value,
     },
+    /// Operands are boxed to keep [`SqlError`] small, since it's the error
+    /// type of most results in the codebase.
     CannotApplyBinary {
-        left: Expression,
+        left: Box<Expression>,
         operator: BinaryOperator,
-        right: Expression,
+        right: Box<Expression>,
     },
     ExpectedType {
         expected: VmDataType,
@@ -100,6 +113,34 @@ impl Display for TypeError {
     }
 }
 
+/// Source of the values that identifiers resolve to.
+///
+/// The VM resolves expressions against tuples described by a [`Schema`], but
+/// expressions can also be evaluated against anything else that maps names to
+/// values. See [`super::eval`].
+pub(crate) trait Row {
+    /// Value of the column or variable called `ident`.
+    fn get(&self, ident: &str) -> Option<&Value>;
+}
+
+/// Tuple and the schema that describes it.
+struct SchemaRow<'r> {
+    tuple: &'r [Value],
+    schema: &'r Schema,
+}
+
+impl Row for SchemaRow<'_> {
+    fn get(&self, ident: &str) -> Option<&Value> {
+        self.schema.index_of(ident).map(|index| &self.tuple[index])
+    }
+}
+
+impl Row for HashMap<String, Value> {
+    fn get(&self, ident: &str) -> Option<&Value> {
+        HashMap::get(self, ident)
+    }
+}
+
 /// Reduces an [`Expression`] instance to a concrete [`Value`] if possible.
 ///
 /// If the expression cannot be resolved then this function returns a
@@ -109,44 +150,59 @@ pub(crate) fn resolve_expression(
     schema: &Schema,
     expr: &Expression,
 ) -> Result<Value, SqlError> {
+    resolve(&SchemaRow { tuple, schema }, expr)
+}
+
+/// Same as [`resolve_expression`] but identifiers are looked up in any
+/// [`Row`].
+pub(crate) fn resolve(row: &impl Row, expr: &Expression) -> Result<Value, SqlError> {
     match expr {
         Expression::Value(value) => Ok(value.clone()), // TODO: Avoid cloning
 
-        Expression::Identifier(ident) => match schema.index_of(ident) {
-            Some(index) => Ok(tuple[index].clone()),
+        Expression::Identifier(ident) => match row.get(ident) {
+            Some(value) => Ok(value.clone()),
             None => Err(SqlError::InvalidColumn(ident.clone())),
         },
 
-        Expression::UnaryOperation { operator, expr } => {
-            match resolve_expression(tuple, schema, expr)? {
-                Value::Number(mut num) => {
-                    if let UnaryOperator::Minus = operator {
-                        num = -num;
-                    }
-
-                    Ok(Value::Number(num))
+        Expression::UnaryOperation { operator, expr } => match (operator, resolve(row, expr)?) {
+            (UnaryOperator::Plus | UnaryOperator::Minus, Value::Number(mut num)) => {
+                if let UnaryOperator::Minus = operator {
+                    num = -num;
                 }
 
-                value => Err(SqlError::TypeError(TypeError::CannotApplyUnary {
-                    operator: *operator,
-                    value,
-                })),
+                Ok(Value::Number(num))
             }
-        }
+
+            (UnaryOperator::Not, Value::Bool(bool)) => Ok(Value::Bool(!bool)),
+
+            (_, value) => Err(SqlError::TypeError(TypeError::CannotApplyUnary {
+                operator: *operator,
+                value,
+            })),
+        },
 
         Expression::BinaryOperation {
             left,
             operator,
             right,
         } => {
-            let left = resolve_expression(tuple, schema, left)?;
-            let right = resolve_expression(tuple, schema, right)?;
+            let left = resolve(row, left)?;
+
+            // AND and OR short-circuit, the right side is not even evaluated
+            // if the left side already determines the result.
+            match (operator, &left) {
+                (BinaryOperator::And, Value::Bool(false)) => return Ok(Value::Bool(false)),
+                (BinaryOperator::Or, Value::Bool(true)) => return Ok(Value::Bool(true)),
+                _ => {}
+            }
+
+            let right = resolve(row, right)?;
 
             let mismatched_types = || {
                 SqlError::TypeError(TypeError::CannotApplyBinary {
-                    left: Expression::Value(left.clone()),
+                    left: Box::new(Expression::Value(left.clone())),
                     operator: *operator,
-                    right: Expression::Value(right.clone()),
+                    right: Box::new(Expression::Value(right.clone())),
                 })
             };
 
@@ -174,32 +230,259 @@ pub(crate) fn resolve_expression(
                     }
                 }
 
-                arithmetic => {
-                    let (Value::Number(left), Value::Number(right)) = (&left, &right) else {
-                        return Err(mismatched_types());
-                    };
+                arithmetic => resolve_arithmetic(&left, *arithmetic, &right)?,
+            })
+        }
 
-                    if arithmetic == &BinaryOperator::Div && *right == 0 {
-                        return Err(VmError::DivisionByZero(*left, *right).into());
-                    }
+        Expression::Nested(expr) => resolve(row, expr),
 
-                    Value::Number(match arithmetic {
-                        BinaryOperator::Plus => left + right,
-                        BinaryOperator::Minus => left - right,
-                        BinaryOperator::Mul => left * right,
-                        BinaryOperator::Div => left / right,
-                        _ => unreachable!("unhandled arithmetic operator: {arithmetic}"),
-                    })
+        Expression::InList {
+            expr,
+            list,
+            negated,
+        } => {
+            let value = resolve(row, expr)?;
+
+            let mut found = false;
+            for item in list {
+                if resolve(row, item)? == value {
+                    found = true;
+                    break;
                 }
-            })
+            }
+
+            Ok(Value::Bool(found != *negated))
         }
 
-        Expression::Nested(expr) => resolve_expression(tuple, schema, expr),
+        Expression::Between {
+            expr,
+            low,
+            high,
+            negated,
+        } => {
+            let value = resolve(row, expr)?;
+            let low = resolve(row, low)?;
+            let high = resolve(row, high)?;
+
+            if mem::discriminant(&value) != mem::discriminant(&low)
+                || mem::discriminant(&value) != mem::discriminant(&high)
+            {
+                return Err(SqlError::Other(format!(
+                    "cannot compare {value} with BETWEEN bounds {low} and {high}"
+                )));
+            }
+
+            let between = low <= value && value <= high;
+
+            Ok(Value::Bool(between != *negated))
+        }
+
+        Expression::Like {
+            expr,
+            pattern,
+            escape,
+            case_insensitive,
+        } => match (resolve(row, expr)?, resolve(row, pattern)?) {
+            (Value::String(string), Value::String(pattern)) => Ok(Value::Bool(like(
+                &string,
+                &pattern,
+                *escape,
+                *case_insensitive,
+            )?)),
+
+            (Value::String(_), other) | (other, _) => {
+                Err(SqlError::TypeError(TypeError::ExpectedType {
+                    expected: VmDataType::String,
+                    found: Expression::Value(other),
+                }))
+            }
+        },
+
+        Expression::IsNull { expr, negated } => {
+            let is_null = resolve(row, expr)? == Value::Null;
+            Ok(Value::Bool(is_null != *negated))
+        }
+
+        Expression::Case {
+            operand,
+            when_then,
+            else_result,
+        } => {
+            // Searched CASE is the same as comparing every condition to TRUE.
+            let operand = match operand {
+                Some(operand) => resolve(row, operand)?,
+                None => Value::Bool(true),
+            };
+
+            for (condition, result) in when_then {
+                if resolve(row, condition)? == operand {
+                    return resolve(row, result);
+                }
+            }
+
+            match else_result {
+                Some(else_result) => resolve(row, else_result),
+                None => Ok(Value::Null),
+            }
+        }
+
+        Expression::Function { name, .. } => Err(SqlError::Other(format!(
+            "function {name}() is not supported yet"
+        ))),
 
         Expression::Wildcard => {
             unreachable!("wildcards should be resolved into identifiers at this point")
         }
+
+        Expression::Cast { .. } => Err(SqlError::Other("CAST is not supported yet".into())),
+
+        Expression::Row(_) => Err(SqlError::Other("row values are not supported yet".into())),
+
+        Expression::Parameter(_) | Expression::NamedParameter(_) => {
+            Err(SqlError::Other("parameters are not supported yet".into()))
+        }
+
+        Expression::Subquery(_)
+        | Expression::Exists { .. }
+        | Expression::InSubquery { .. }
+        | Expression::Quantified { .. } => {
+            Err(SqlError::Other("subqueries are not supported yet".into()))
+        }
+
+        Expression::QualifiedIdentifier { .. } | Expression::QualifiedWildcard(_) => Err(
+            SqlError::Other("qualified identifiers are not supported yet".into()),
+        ),
+    }
+}
+
+/// Computes `left {operator} right` where `operator` is one of the arithmetic
+/// operators.
+///
+/// Only works on numbers. Division by zero and integer overflow are returned
+/// as [`VmError`] instead of panicking.
+pub(crate) fn resolve_arithmetic(
+    left: &Value,
+    operator: BinaryOperator,
+    right: &Value,
+) -> Result<Value, SqlError> {
+    let (Value::Number(left), Value::Number(right)) = (left, right) else {
+        return Err(SqlError::TypeError(TypeError::CannotApplyBinary {
+            left: Box::new(Expression::Value(left.clone())),
+            operator,
+            right: Box::new(Expression::Value(right.clone())),
+        }));
+    };
+
+    if matches!(operator, BinaryOperator::Div | BinaryOperator::Mod) && *right == 0 {
+        return Err(VmError::DivisionByZero(*left, *right).into());
+    }
+
+    let result = match operator {
+        BinaryOperator::Plus => left.checked_add(*right),
+        BinaryOperator::Minus => left.checked_sub(*right),
+        BinaryOperator::Mul => left.checked_mul(*right),
+        BinaryOperator::Div => left.checked_div(*right),
+        BinaryOperator::Mod => left.checked_rem(*right),
+        BinaryOperator::BitAnd => Some(left & right),
+        BinaryOperator::BitOr => Some(left | right),
+        BinaryOperator::BitXor => Some(left ^ right),
+        // checked_shl() only fails when shifting by 128 bits or more, bits
+        // shifted out of the number must be detected by shifting back.
+        BinaryOperator::ShiftLeft => u32::try_from(*right).ok().and_then(|shift| {
+            left.checked_shl(shift)
+                .filter(|result| result >> shift == *left)
+        }),
+        BinaryOperator::ShiftRight => u32::try_from(*right)
+            .ok()
+            .and_then(|shift| left.checked_shr(shift)),
+        _ => unreachable!("unhandled arithmetic operator: {operator}"),
+    };
+
+    result.map(Value::Number).ok_or_else(|| {
+        VmError::Overflow {
+            left: *left,
+            operator,
+            right: *right,
+        }
+        .into()
+    })
+}
+
+/// Element of a `LIKE` pattern once escape characters have been resolved.
+#[derive(PartialEq)]
+enum LikeToken {
+    /// `%`.
+    AnyString,
+    /// `_`.
+    AnyChar,
+    Literal(char),
+}
+
+/// Matches `string` against a SQL `LIKE` pattern.
+///
+/// The `%` wildcard matches any sequence of characters (including none) and
+/// `_` matches exactly one character. Both of them match literally when
+/// preceded by the `escape` character. When a character doesn't match we
+/// backtrack to the last `%` seen and let it consume one more character.
+///
+/// If `case_insensitive` is set (`ILIKE`) literal characters are compared
+/// ignoring case, but the escape character must still match exactly.
+fn like(
+    string: &str,
+    pattern: &str,
+    escape: Option<char>,
+    case_insensitive: bool,
+) -> Result<bool, SqlError> {
+    let string: Vec<char> = string.chars().collect();
+
+    let mut pattern_chars = pattern.chars();
+    let mut pattern = Vec::new();
+
+    while let Some(chr) = pattern_chars.next() {
+        pattern.push(match chr {
+            _ if Some(chr) == escape => match pattern_chars.next() {
+                Some(escaped) => LikeToken::Literal(escaped),
+                None => Err(SqlError::Other(
+                    "LIKE pattern must not end with escape character".into(),
+                ))?,
+            },
+            '%' => LikeToken::AnyString,
+            '_' => LikeToken::AnyChar,
+            _ => LikeToken::Literal(chr),
+        });
+    }
+
+    let matches = |token: &LikeToken, chr: char| match token {
+        LikeToken::AnyChar => true,
+        LikeToken::Literal(literal) if case_insensitive => {
+            literal.to_lowercase().eq(chr.to_lowercase())
+        }
+        LikeToken::Literal(literal) => *literal == chr,
+        LikeToken::AnyString => false,
+    };
+
+    let (mut s, mut p) = (0, 0);
+    let mut last_wildcard = None;
+
+    while s < string.len() {
+        if p < pattern.len() && pattern[p] == LikeToken::AnyString {
+            p += 1;
+            last_wildcard = Some((p, s));
+        } else if p < pattern.len() && matches(&pattern[p], string[s]) {
+            p += 1;
+            s += 1;
+        } else if let Some((wildcard_p, wildcard_s)) = last_wildcard {
+            p = wildcard_p;
+            s = wildcard_s + 1;
+            last_wildcard = Some((wildcard_p, s));
+        } else {
+            return Ok(false);
+        }
     }
+
+    Ok(pattern[p..]
+        .iter()
+        .all(|token| *token == LikeToken::AnyString))
 }
 
 /// Same as [`resolve_expression`] but without variables.
@@ -229,7 +512,7 @@ pub(crate) fn eval_where(
 
 #[cfg(test)]
 mod tests {
-    use super::VmError;
+    use super::{BinaryOperator, VmError};
     use crate::{
         db::{DbError, Schema, SqlError},
         sql::{
@@ -341,6 +624,252 @@ mod tests {
         })
     }
 
+    #[test]
+    fn resolve_like() -> Result<(), DbError> {
+        for (pattern, expected) in [
+            ("A%", true),
+            ("%ice", true),
+            ("%l_c%", true),
+            ("A_ce", false),
+            ("_lice", true),
+            ("%", true),
+            ("Bob%", false),
+        ] {
+            assert_resolve(Resolve {
+                expression: &format!("name LIKE '{pattern}'"),
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("name", DataType::Varchar(255))]),
+                    tuple: vec![Value::String("Alice".into())],
+                },
+                expected: Ok(Value::Bool(expected)),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_ilike() -> Result<(), DbError> {
+        for (expression, expected) in [
+            ("name LIKE 'alice'", false),
+            ("name ILIKE 'alice'", true),
+            ("name LIKE 'AL%'", true),
+            ("name ILIKE 'al%'", true),
+            ("name ILIKE '_LICE'", true),
+            ("name ILIKE 'bob%'", false),
+            ("name ILIKE 'AL' ESCAPE 'l'", false),
+            ("name ILIKE 'ALICE' ESCAPE 'l'", true),
+            ("name ILIKE 'AlLICE' ESCAPE 'l'", true),
+            ("'ax' ILIKE 'ax' ESCAPE 'X'", true),
+            ("'aX' ILIKE 'aXX' ESCAPE 'X'", true),
+            ("'aX' ILIKE 'aX' ESCAPE 'x'", true),
+        ] {
+            assert_resolve(Resolve {
+                expression,
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("name", DataType::Varchar(255))]),
+                    tuple: vec![Value::String("ALice".into())],
+                },
+                expected: Ok(Value::Bool(expected)),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_like_with_escape() -> Result<(), DbError> {
+        for (expression, expected) in [
+            (r"name LIKE '100\%' ESCAPE '\'", Ok(Value::Bool(true))),
+            (r"name LIKE '1_0\%' ESCAPE '\'", Ok(Value::Bool(true))),
+            ("name LIKE '%!%' ESCAPE '!'", Ok(Value::Bool(true))),
+            ("name LIKE '%!_' ESCAPE '!'", Ok(Value::Bool(false))),
+            ("name LIKE '100!!' ESCAPE '!'", Ok(Value::Bool(false))),
+            (
+                "name LIKE '100!' ESCAPE '!'",
+                Err(SqlError::Other(
+                    "LIKE pattern must not end with escape character".into(),
+                )),
+            ),
+        ] {
+            assert_resolve(Resolve {
+                expression,
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("name", DataType::Varchar(255))]),
+                    tuple: vec![Value::String("100%".into())],
+                },
+                expected,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_in_list() -> Result<(), DbError> {
+        for (expression, expected) in [
+            ("x IN (1, 2, 3)", true),
+            ("x IN (4, 5)", false),
+            ("x NOT IN (4, 5)", true),
+            ("x NOT IN (x + 1, 2 + 1)", false),
+        ] {
+            assert_resolve(Resolve {
+                expression,
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("x", DataType::Int)]),
+                    tuple: vec![Value::Number(3)],
+                },
+                expected: Ok(Value::Bool(expected)),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_between() -> Result<(), DbError> {
+        for (expression, expected) in [
+            ("x BETWEEN 1 AND 10", true),
+            ("x BETWEEN 5 AND 5", true),
+            ("x BETWEEN 6 AND 10", false),
+            ("x NOT BETWEEN 6 AND 10", true),
+        ] {
+            assert_resolve(Resolve {
+                expression,
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("x", DataType::Int)]),
+                    tuple: vec![Value::Number(5)],
+                },
+                expected: Ok(Value::Bool(expected)),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_case() -> Result<(), DbError> {
+        for (expression, expected) in [
+            ("CASE WHEN x > 10 THEN 'hi' ELSE 'lo' END", "lo"),
+            ("CASE WHEN x < 10 THEN 'hi' ELSE 'lo' END", "hi"),
+            ("CASE x WHEN 1 THEN 'one' WHEN 5 THEN 'five' END", "five"),
+            ("CASE x + 1 WHEN 5 THEN 'five' ELSE 'other' END", "other"),
+        ] {
+            assert_resolve(Resolve {
+                expression,
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("x", DataType::Int)]),
+                    tuple: vec![Value::Number(5)],
+                },
+                expected: Ok(Value::String(expected.into())),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_case_without_else() -> Result<(), DbError> {
+        assert_resolve(Resolve {
+            expression: "CASE WHEN x > 10 THEN 1 END",
+            vm_context: VmCtx {
+                schema: Schema::new(vec![Column::new("x", DataType::Int)]),
+                tuple: vec![Value::Number(5)],
+            },
+            expected: Ok(Value::Null),
+        })
+    }
+
+    #[test]
+    fn resolve_not() -> Result<(), DbError> {
+        assert_resolve(Resolve {
+            expression: "NOT x > 5 AND NOT NOT x = 1",
+            vm_context: VmCtx {
+                schema: Schema::new(vec![Column::new("x", DataType::Int)]),
+                tuple: vec![Value::Number(1)],
+            },
+            expected: Ok(Value::Bool(true)),
+        })
+    }
+
+    #[test]
+    fn resolve_modulo() -> Result<(), DbError> {
+        for (expression, expected) in [("x % 4", 3), ("x % 4 * 2", 6), ("2 * x % 4", 2)] {
+            assert_resolve(Resolve {
+                expression,
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("x", DataType::Int)]),
+                    tuple: vec![Value::Number(7)],
+                },
+                expected: Ok(Value::Number(expected)),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_bitwise() -> Result<(), DbError> {
+        for (expression, expected) in [
+            ("x & 3", Ok(Value::Number(2))),
+            ("x | 5", Ok(Value::Number(15))),
+            ("x ^ 15", Ok(Value::Number(5))),
+            ("x << 2", Ok(Value::Number(40))),
+            ("x >> 1", Ok(Value::Number(5))),
+            ("x & 1 << 3", Ok(Value::Number(8))),
+            ("x | 1 = 11", Ok(Value::Bool(true))),
+            ("-x << 123", Ok(Value::Number(-10 << 123))),
+            (
+                "x << 200",
+                Err(VmError::Overflow {
+                    left: 10,
+                    operator: BinaryOperator::ShiftLeft,
+                    right: 200,
+                }
+                .into()),
+            ),
+        ] {
+            assert_resolve(Resolve {
+                expression,
+                vm_context: VmCtx {
+                    schema: Schema::new(vec![Column::new("x", DataType::Int)]),
+                    tuple: vec![Value::Number(10)],
+                },
+                expected,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn shift_left_overflow() -> Result<(), DbError> {
+        for (left, right) in [(1, 127), (3, 126), (10, 125)] {
+            assert_resolve(Resolve {
+                expression: &format!("{left} << {right}"),
+                vm_context: VmCtx {
+                    schema: Schema::empty(),
+                    tuple: vec![],
+                },
+                expected: Err(VmError::Overflow {
+                    left,
+                    operator: BinaryOperator::ShiftLeft,
+                    right,
+                }
+                .into()),
+            })?;
+        }
+
+        assert_resolve(Resolve {
+            expression: "1 << 126",
+            vm_context: VmCtx {
+                schema: Schema::empty(),
+                tuple: vec![],
+            },
+            expected: Ok(Value::Number(1 << 126)),
+        })
+    }
+
     #[test]
     fn division_by_zero() -> Result<(), DbError> {
         assert_resolve(Resolve {
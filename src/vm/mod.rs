@@ -16,6 +16,8 @@
 
 mod expression;
 
+#[cfg(test)]
+pub(crate) mod eval;
 pub(crate) mod plan;
 pub(crate) mod statement;
 
@@ -63,7 +63,7 @@ use crate::{
         io::FileOps,
         pager::{PageNumber, Pager},
     },
-    sql::statement::{join, Assignment, Expression, Value},
+    sql::statement::{join, Assignment, Constraint, Expression, Value},
     storage::{
         reassemble_payload, tuple, BTree, BTreeKeyComparator, BytesCmp, Cursor, FixedSizeMemCmp,
     },
@@ -819,6 +819,8 @@ impl<F: Seek + Read + Write + FileOps> Insert<F> {
             return Ok(None);
         };
 
+        check_constraints(&self.table, &tuple)?;
+
         let mut pager = self.pager.borrow_mut();
 
         // TODO: We know that all tables use integers as BTree keys whereas
@@ -854,6 +856,27 @@ impl<F: Seek + Read + Write + FileOps> Insert<F> {
     }
 }
 
+/// Returns an error if the tuple doesn't satisfy all the `CHECK` constraints
+/// defined in the table, either on columns or at the table level.
+fn check_constraints(table: &TableMetadata, tuple: &Tuple) -> Result<(), DbError> {
+    let column_checks = table.schema.columns.iter().flat_map(|col| {
+        col.constraints
+            .iter()
+            .filter_map(|constraint| match constraint {
+                Constraint::Check(expr) => Some(expr),
+                _ => None,
+            })
+    });
+
+    for expr in column_checks.chain(&table.checks) {
+        if vm::resolve_expression(tuple, &table.schema, expr)? != Value::Bool(true) {
+            return Err(SqlError::CheckViolation(expr.clone()).into());
+        }
+    }
+
+    Ok(())
+}
+
 impl<F> Display for Insert<F> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Insert on table '{}'", self.table.name)
@@ -899,6 +922,8 @@ impl<F: Seek + Read + Write + FileOps> Update<F> {
             }
         }
 
+        check_constraints(&self.table, &tuple)?;
+
         let mut pager = self.pager.borrow_mut();
         let mut btree = BTree::new(&mut pager, self.table.root, self.comparator);
 
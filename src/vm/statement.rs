@@ -40,7 +40,18 @@ pub(crate) fn exec<F: Seek + Read + Write + FileOps>(
     let mut affected_rows = 0;
 
     match statement {
-        Statement::Create(Create::Table { name, columns }) => {
+        Statement::Create(Create::Table {
+            name,
+            columns,
+            if_not_exists,
+            ..
+        }) => {
+            // The analyzer lets this statement through when the table already
+            // exists, in which case it does nothing.
+            if if_not_exists && db.table_metadata(&name).is_ok() {
+                return Ok(affected_rows);
+            }
+
             let root = alloc_root_page(db)?;
 
             insert_into_mkdb_meta(db, vec![
@@ -59,18 +70,23 @@ pub(crate) fn exec<F: Seek + Read + Write + FileOps>(
                 .filter(|col| !col.constraints.is_empty())
                 .flat_map(|col| {
                     let table_name = name.clone();
-                    col.constraints.into_iter().map(move |constraint| {
+                    col.constraints.into_iter().filter_map(move |constraint| {
                         let index_name = match constraint {
                             Constraint::PrimaryKey => format!("{table_name}_pk_index"),
                             Constraint::Unique => format!("{table_name}_{}_uq_index", &col.name),
+                            Constraint::NotNull
+                            | Constraint::References { .. }
+                            | Constraint::Default(_)
+                            | Constraint::Check(_)
+                            | Constraint::AutoIncrement => return None,
                         };
 
-                        Create::Index {
+                        Some(Create::Index {
                             name: index_name,
                             table: table_name.clone(),
-                            column: col.name.clone(),
+                            columns: vec![col.name.clone()],
                             unique: true,
-                        }
+                        })
                     })
                 });
 
@@ -82,7 +98,7 @@ pub(crate) fn exec<F: Seek + Read + Write + FileOps>(
         Statement::Create(Create::Index {
             name,
             table,
-            column,
+            mut columns,
             unique,
         }) => {
             if !unique {
@@ -102,7 +118,9 @@ pub(crate) fn exec<F: Seek + Read + Write + FileOps>(
                 Value::String(sql),
             ])?;
 
-            // Now build up the index.
+            // Now build up the index. The analyzer only lets single column
+            // indexes through.
+            let column = columns.swap_remove(0);
             let metadata = db.table_metadata(&table)?;
 
             let col = metadata
@@ -153,7 +171,7 @@ pub(crate) fn exec<F: Seek + Read + Write + FileOps>(
             db.context.invalidate(&table);
         }
 
-        Statement::Drop(Drop::Table(name)) => {
+        Statement::Drop(Drop::Table { name, .. }) => {
             let comparator = db.table_metadata(MKDB_META)?.comparator()?;
 
             let mut plan = collect_from_mkdb_meta_where(db, &format!("table_name = '{name}'"))?;